@@ -0,0 +1,482 @@
+use crate::bond::BondType;
+use crate::communication::{CommunicationTone, CommunicationType};
+use crate::dragon::Dragon;
+use crate::element;
+use crate::locale;
+use crate::relationship::DimensionChange;
+
+/// Chance that a dragon with a ready ability uses it instead of communicating
+pub const ABILITY_CHANCE: f64 = 0.2;
+
+/// Signature action of each element
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AbilityKind {
+    /// Fire: a show of flame that makes the target fear and respect the actor
+    IntimidationDisplay,
+    /// Water: tend to another dragon, earning their affection and trust
+    Healing,
+    /// Earth: strengthen the clan's lair, earning everyone's respect
+    LairBuilding,
+    /// Wind: scout the clan's territory, earning everyone's trust
+    Scouting,
+    /// Lightning: a formal challenge that counts as a duel
+    LightningChallenge,
+    /// Ice: quiet reflection that softens the actor's grudges
+    Meditation,
+}
+
+impl AbilityKind {
+    /// Signature ability of an element, as named in the element registry
+    pub fn for_element(element: &str) -> Option<Self> {
+        element::with_element(element, |e| e.ability.clone())
+            .flatten()
+            .and_then(|name| AbilityKind::from_str(&name))
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "intimidation display" => Some(AbilityKind::IntimidationDisplay),
+            "healing" => Some(AbilityKind::Healing),
+            "lair-building" => Some(AbilityKind::LairBuilding),
+            "scouting" => Some(AbilityKind::Scouting),
+            "lightning challenge" => Some(AbilityKind::LightningChallenge),
+            "meditation" => Some(AbilityKind::Meditation),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AbilityKind::IntimidationDisplay => "intimidation display",
+            AbilityKind::Healing => "healing",
+            AbilityKind::LairBuilding => "lair-building",
+            AbilityKind::Scouting => "scouting",
+            AbilityKind::LightningChallenge => "lightning challenge",
+            AbilityKind::Meditation => "meditation",
+        }
+    }
+
+    /// The ability's name in the current locale
+    pub fn display_name(&self) -> String {
+        locale::name("ability.name", &self.key())
+    }
+
+    /// What the ability does, in the current locale
+    pub fn summary(&self) -> String {
+        locale::render(&format!("ability.summary.{}", self.key()), &[])
+    }
+
+    /// Catalog key for the ability, e.g. "lair_building"
+    fn key(&self) -> String {
+        self.as_str().replace([' ', '-'], "_")
+    }
+
+    /// Days before the ability can be used again
+    pub fn cooldown_days(&self) -> u32 {
+        match self {
+            AbilityKind::IntimidationDisplay => 2,
+            AbilityKind::Healing => 3,
+            AbilityKind::LairBuilding => 5,
+            AbilityKind::Scouting => 3,
+            AbilityKind::LightningChallenge => 2,
+            AbilityKind::Meditation => 4,
+        }
+    }
+
+    /// Whether the ability is aimed at another dragon rather than the clan or the actor
+    pub fn targets_other(&self) -> bool {
+        matches!(
+            self,
+            AbilityKind::IntimidationDisplay
+                | AbilityKind::Healing
+                | AbilityKind::LightningChallenge
+        )
+    }
+
+    /// How an ability aimed at another dragon reads as an exchange between the two
+    /// Used for their memories, the clan's mood and the bonds they form; a challenge counts as a duel
+    pub fn exchange(&self) -> Option<(CommunicationType, CommunicationTone)> {
+        match self {
+            AbilityKind::IntimidationDisplay => Some((CommunicationType::Confrontational, CommunicationTone::Negative)),
+            AbilityKind::Healing => Some((CommunicationType::Friendly, CommunicationTone::Warm)),
+            AbilityKind::LightningChallenge => {
+                Some((CommunicationType::Confrontational, CommunicationTone::Challenging))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Highest clan morale abilities can build up to
+const MAX_MORALE: i32 = 100;
+
+/// Clan-wide state that abilities build up
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ClanState {
+    /// How well-built the clan's lair is (0-100)
+    pub lair_strength: u32,
+    /// How much of the surrounding territory has been scouted (0-100)
+    pub scouted_territory: u32,
+    /// General mood of the clan (0-100)
+    pub morale: i32,
+}
+
+impl ClanState {
+    fn raise_morale(&mut self) {
+        self.morale = (self.morale + 1).min(MAX_MORALE);
+    }
+}
+
+/// Pick who the actor aims its ability at
+/// Returns None if the ability doesn't target another dragon or there is no suitable one
+pub fn choose_target(dragons: &[Dragon], actor: usize, kind: AbilityKind) -> Option<usize> {
+    let others = (0..dragons.len()).filter(|&i| i != actor);
+    match kind {
+        // Intimidate the dragon the actor dislikes most
+        AbilityKind::IntimidationDisplay => others
+            .filter(|&i| dragons[actor].get_opinion_of(&dragons[i]) < 0)
+            .min_by_key(|&i| dragons[actor].get_opinion_of(&dragons[i])),
+        // Tend to the dragon that trusts the actor least
+        AbilityKind::Healing => others.min_by_key(|&i| {
+            dragons[i]
+                .relationship_with(&dragons[actor])
+                .map(|r| r.trust())
+                .unwrap_or(0)
+        }),
+        // Challenge the most dominant rival: a dragon the actor dislikes or has sworn rivalry with
+        AbilityKind::LightningChallenge => others
+            .filter(|&i| {
+                dragons[actor].get_opinion_of(&dragons[i]) < 0
+                    || dragons[actor].bond_types_with(&dragons[i]).contains(&BondType::SwornRival)
+            })
+            .max_by_key(|&i| dragons[i].dominance()),
+        _ => None,
+    }
+}
+
+/// Apply an ability's effects on relationships and clan state
+/// target is None for abilities that don't target another dragon
+/// Returns a description of what happened and the target's opinion change of the actor,
+/// or None if the ability needs a target and none was given
+pub fn use_ability(
+    dragons: &mut [Dragon],
+    actor: usize,
+    target: Option<usize>,
+    kind: AbilityKind,
+    state: &mut ClanState,
+    day: u32,
+) -> Option<(String, i32)> {
+    let actor_clone = dragons[actor].clone();
+    let actor_name = actor_clone.name();
+    let actor_element = actor_clone.element_label();
+    let target_name = target.map(|target| dragons[target].name()).unwrap_or_default();
+    // Describe what happened in the current locale
+    let describe = |key: &str, details: &[(&str, &str)]| {
+        let mut placeholders = vec![
+            ("actor", actor_name.as_str()),
+            ("actor_element", actor_element.as_str()),
+            ("target", target_name.as_str()),
+        ];
+        placeholders.extend_from_slice(details);
+        locale::render(key, &placeholders)
+    };
+
+    let outcome = match (kind, target) {
+        (AbilityKind::IntimidationDisplay, Some(target)) => {
+            let opinion_change = -3;
+            affect(
+                dragons,
+                target,
+                &actor_clone,
+                opinion_change,
+                DimensionChange {
+                    respect: 5,
+                    trust: 0,
+                    fear: 15,
+                },
+                day,
+            );
+            let target_clone = dragons[target].clone();
+            affect(
+                dragons,
+                actor,
+                &target_clone,
+                0,
+                DimensionChange {
+                    respect: -2,
+                    trust: 0,
+                    fear: 0,
+                },
+                day,
+            );
+            (
+                describe("ability.intimidation_display", &[]),
+                opinion_change,
+            )
+        }
+        (AbilityKind::Healing, Some(target)) => {
+            let opinion_change = 10;
+            affect(
+                dragons,
+                target,
+                &actor_clone,
+                opinion_change,
+                DimensionChange {
+                    respect: 0,
+                    trust: 8,
+                    fear: -5,
+                },
+                day,
+            );
+            state.raise_morale();
+            (
+                describe("ability.healing", &[]),
+                opinion_change,
+            )
+        }
+        (AbilityKind::LairBuilding, _) => {
+            state.lair_strength = (state.lair_strength + 5).min(100);
+            state.raise_morale();
+            affect_acquaintances(
+                dragons,
+                actor,
+                DimensionChange {
+                    respect: 2,
+                    trust: 0,
+                    fear: 0,
+                },
+            );
+            (
+                describe("ability.lair_building", &[("lair_strength", &state.lair_strength.to_string())]),
+                0,
+            )
+        }
+        (AbilityKind::Scouting, _) => {
+            state.scouted_territory = (state.scouted_territory + 5).min(100);
+            affect_acquaintances(
+                dragons,
+                actor,
+                DimensionChange {
+                    respect: 0,
+                    trust: 1,
+                    fear: 0,
+                },
+            );
+            (
+                describe("ability.scouting", &[("explored", &state.scouted_territory.to_string())]),
+                0,
+            )
+        }
+        (AbilityKind::LightningChallenge, Some(target)) => {
+            let opinion_change = -5;
+            affect(
+                dragons,
+                target,
+                &actor_clone,
+                opinion_change,
+                DimensionChange {
+                    respect: 4,
+                    trust: -2,
+                    fear: 2,
+                },
+                day,
+            );
+            let target_clone = dragons[target].clone();
+            affect(
+                dragons,
+                actor,
+                &target_clone,
+                0,
+                DimensionChange {
+                    respect: 4,
+                    trust: 0,
+                    fear: 0,
+                },
+                day,
+            );
+            (
+                describe("ability.lightning_challenge", &[]),
+                opinion_change,
+            )
+        }
+        (AbilityKind::Meditation, _) => {
+            dragons[actor].soften_grudges(0.1);
+            state.raise_morale();
+            (
+                describe("ability.meditation", &[]),
+                0,
+            )
+        }
+        (AbilityKind::IntimidationDisplay | AbilityKind::Healing | AbilityKind::LightningChallenge, None) => {
+            return None;
+        }
+    };
+    Some(outcome)
+}
+
+/// Change how the dragon at holder sees other
+fn affect(
+    dragons: &mut [Dragon],
+    holder: usize,
+    other: &Dragon,
+    opinion_change: i32,
+    change: DimensionChange,
+    day: u32,
+) {
+    let relationship = dragons[holder].relationship_with_mut(&other.name());
+    if opinion_change != 0 {
+        relationship.update_opinion(opinion_change);
+    }
+    relationship.update_dimensions(change);
+    relationship.record_contact(day);
+}
+
+/// Change how every dragon that already knows the actor sees it
+/// Dragons that have never met the actor are left alone, and nobody counts it as contact
+fn affect_acquaintances(dragons: &mut [Dragon], actor: usize, change: DimensionChange) {
+    let actor_name = dragons[actor].name();
+    for (i, dragon) in dragons.iter_mut().enumerate() {
+        if i == actor {
+            continue;
+        }
+        if let Some(relationship) = dragon.existing_relationship_mut(&actor_name) {
+            relationship.update_dimensions(change);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clan::DragonClan;
+
+    #[test]
+    fn test_healing_earns_trust_and_cooldown() {
+        let mut dragons = vec![
+            Dragon::new("Aqua".to_string(), "Water".to_string(), 10),
+            Dragon::new("Ignis".to_string(), "Fire".to_string(), 10),
+        ];
+        let mut state = ClanState::default();
+
+        let kind = dragons[0].ability().unwrap();
+        assert_eq!(kind, AbilityKind::Healing);
+        assert_eq!(choose_target(&dragons, 0, kind), Some(1));
+
+        let (_, opinion_change) = use_ability(&mut dragons, 0, Some(1), kind, &mut state, 3).unwrap();
+        dragons[0].start_ability_cooldown(3);
+
+        let healer = dragons[0].clone();
+        let relationship = dragons[1].relationship_with(&healer).unwrap();
+        assert!(opinion_change > 0);
+        assert!(relationship.opinion() > 0);
+        assert!(relationship.trust() > 0);
+        assert_eq!(state.morale, 1);
+        assert_eq!(dragons[0].ability_cooldown(4), 2);
+        assert_eq!(dragons[0].ability_cooldown(6), 0);
+
+        // Morale is capped like the lair and the scouted territory
+        state.morale = MAX_MORALE;
+        use_ability(&mut dragons, 0, Some(1), kind, &mut state, 6).unwrap();
+        assert_eq!(state.morale, MAX_MORALE);
+    }
+
+    #[test]
+    fn test_repeated_challenges_are_remembered_and_swear_rivalry() {
+        let mut clan = DragonClan::new("The Test Clan".to_string());
+        clan.add_dragon(Dragon::new("Volt".to_string(), "Lightning".to_string(), 10));
+        clan.add_dragon(Dragon::new("Terra".to_string(), "Earth".to_string(), 10));
+
+        let mut bonds = Vec::new();
+        for _ in 0..3 {
+            let (target, result) = clan.use_ability(0, Some(1)).unwrap();
+            assert_eq!(target, Some(1));
+            bonds.push(result.new_bond());
+            clan.advance_days(AbilityKind::LightningChallenge.cooldown_days());
+        }
+        assert_eq!(bonds, vec![None, None, Some(BondType::SwornRival)]);
+
+        // A challenge is confrontational, so both sides remember every one
+        let memories = clan.get_memories_by_index(1).unwrap();
+        assert_eq!(memories.len(), 3);
+        assert!(memories.iter().all(|m| !m.initiated && m.other_name == "Volt"));
+        assert_eq!(clan.get_memories_by_index(0).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_targets_are_chosen_by_ability() {
+        let mut dragons = vec![
+            Dragon::new("Ignis".to_string(), "Fire".to_string(), 10),
+            Dragon::new("Aqua".to_string(), "Water".to_string(), 10),
+            Dragon::new("Volt".to_string(), "Lightning".to_string(), 10),
+            Dragon::new("Terra".to_string(), "Earth".to_string(), 10),
+        ];
+
+        // Intimidation needs someone the actor dislikes, and picks the most disliked
+        assert_eq!(choose_target(&dragons, 0, AbilityKind::IntimidationDisplay), None);
+        dragons[0].relationship_with_mut("Volt").update_opinion(-10);
+        dragons[0].relationship_with_mut("Aqua").update_opinion(-40);
+        assert_eq!(choose_target(&dragons, 0, AbilityKind::IntimidationDisplay), Some(1));
+
+        // Healing goes to whoever trusts the healer least
+        dragons[0].relationship_with_mut("Aqua").update_dimensions(DimensionChange {
+            respect: 0,
+            trust: -20,
+            fear: 0,
+        });
+        assert_eq!(choose_target(&dragons, 1, AbilityKind::Healing), Some(0));
+
+        // A challenge goes to the most dominant rival, never to a dragon the challenger is fond of
+        assert_eq!(choose_target(&dragons, 2, AbilityKind::LightningChallenge), None);
+        dragons[2].relationship_with_mut("Ignis").update_opinion(-10);
+        assert_eq!(choose_target(&dragons, 2, AbilityKind::LightningChallenge), Some(0));
+        dragons[2].form_bond("Aqua", BondType::SwornRival, 0);
+        let strongest = [0, 1].into_iter().max_by_key(|&i| dragons[i].dominance()).unwrap();
+        assert_eq!(choose_target(&dragons, 2, AbilityKind::LightningChallenge), Some(strongest));
+
+        // Clan-wide and personal abilities have no target
+        assert_eq!(choose_target(&dragons, 3, AbilityKind::LairBuilding), None);
+        assert_eq!(choose_target(&dragons, 3, AbilityKind::Meditation), None);
+    }
+
+    #[test]
+    fn test_clan_abilities_only_reach_dragons_that_know_the_actor() {
+        let mut dragons = vec![
+            Dragon::new("Terra".to_string(), "Earth".to_string(), 10),
+            Dragon::new("Aqua".to_string(), "Water".to_string(), 10),
+            Dragon::new("Ignis".to_string(), "Fire".to_string(), 10),
+        ];
+        dragons[1].relationship_with_mut("Terra").record_contact(0);
+        let mut state = ClanState::default();
+
+        let outcome = use_ability(&mut dragons, 0, None, AbilityKind::LairBuilding, &mut state, 4);
+        assert_eq!(outcome.map(|(_, change)| change), Some(0));
+        assert_eq!(state.lair_strength, 5);
+        let terra = dragons[0].clone();
+        assert_eq!(dragons[1].relationship_with(&terra).map(|r| r.respect()), Some(2));
+        assert!(dragons[2].relationship_with(&terra).is_none());
+
+        // Abilities aimed at another dragon do nothing without a target
+        assert!(use_ability(&mut dragons, 2, None, AbilityKind::IntimidationDisplay, &mut state, 4).is_none());
+    }
+
+    #[test]
+    fn test_clan_enforces_ability_cooldowns() {
+        let mut clan = DragonClan::new("The Test Clan".to_string());
+        clan.add_dragon(Dragon::new("Terra".to_string(), "Earth".to_string(), 10));
+        clan.add_dragon(Dragon::new("Aqua".to_string(), "Water".to_string(), 10));
+
+        let (target, result) = clan.use_ability(0, None).unwrap();
+        assert_eq!((target, result.ability()), (None, Some(AbilityKind::LairBuilding)));
+        assert!(clan.use_ability(0, None).is_none());
+
+        // Healing someone must name another dragon, not the healer
+        assert!(clan.use_ability(1, Some(1)).is_none());
+        assert_eq!(clan.use_ability(1, Some(0)).map(|(target, _)| target), Some(Some(0)));
+
+        clan.advance_days(AbilityKind::LairBuilding.cooldown_days() - 1);
+        assert!(clan.use_ability(0, None).is_none());
+        clan.advance_days(1);
+        assert!(clan.use_ability(0, None).is_some());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::chronicle::FRIENDSHIP_OPINION;
+use crate::element;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Affinity points per point of opinion change
+/// Small enough that every affinity in the built-in matrix moves opinions
+const AFFINITY_PER_OPINION_POINT: i32 = 10;
+
+/// How a dragon of one element reacts to communications from a dragon of another element
+/// Affinities range from -100 (clash) to 100 (soothe); pairs not in the matrix are neutral (0)
+#[derive(Debug, Clone)]
+pub struct ElementAffinities {
+    affinities: HashMap<(String, String), i32>,
+}
+
+impl ElementAffinities {
+    /// Affinity of a receiver of receiver_element towards a sender of sender_element
+    pub fn get(&self, sender_element: &str, receiver_element: &str) -> i32 {
+        self.affinities
+            .get(&(sender_element.to_string(), receiver_element.to_string()))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn set(&mut self, sender_element: &str, receiver_element: &str, affinity: i32) {
+        self.affinities.insert(
+            (sender_element.to_string(), receiver_element.to_string()),
+            affinity.clamp(-100, 100),
+        );
+    }
+
+    /// Take the rows and columns of the given elements from the element registry, keeping every other pair
+    fn refresh_elements(&mut self, elements: &[String]) {
+        let involved = |sender: &String, receiver: &String| elements.contains(sender) || elements.contains(receiver);
+        self.affinities.retain(|(sender, receiver), _| !involved(sender, receiver));
+        for (sender, receiver, affinity) in element::affinity_entries() {
+            if involved(&sender, &receiver) {
+                self.set(&sender, &receiver, affinity);
+            }
+        }
+    }
+}
+
+impl Default for ElementAffinities {
+    /// Build the matrix from the affinities in the element registry
+    fn default() -> Self {
+        let mut affinities = ElementAffinities {
+            affinities: HashMap::new(),
+        };
+        for (sender, receiver, affinity) in element::affinity_entries() {
+            affinities.set(&sender, &receiver, affinity);
+        }
+        affinities
+    }
+}
+
+// Global affinity matrix
+// For WASM (single-threaded), we use a simple thread_local with RefCell
+// It is global rather than per clan because it is derived from the global element registry and is
+// rebuilt whenever elements are registered, and because Dragon::interact_with consults it for dragons
+// outside any clan. Edits through one ClanService are therefore seen by every clan on the thread
+thread_local! {
+    static ELEMENT_AFFINITIES: RefCell<ElementAffinities> = RefCell::new(ElementAffinities::default());
+}
+
+/// Affinity of a receiver of receiver_element towards a sender of sender_element
+pub fn get_affinity(sender_element: &str, receiver_element: &str) -> i32 {
+    ELEMENT_AFFINITIES.with(|affinities| affinities.borrow().get(sender_element, receiver_element))
+}
+
+/// Change the affinity of one element pair
+pub fn set_affinity(sender_element: &str, receiver_element: &str, affinity: i32) {
+    ELEMENT_AFFINITIES.with(|affinities| {
+        affinities
+            .borrow_mut()
+            .set(sender_element, receiver_element, affinity)
+    });
+}
+
+/// Update the affinities of newly registered or replaced elements from the element registry
+/// Pairs between other elements, including ones changed with set_affinity, are kept
+pub fn refresh_elements(elements: &[String]) {
+    ELEMENT_AFFINITIES.with(|affinities| affinities.borrow_mut().refresh_elements(elements));
+}
+
+/// Restore the affinity matrix defined by the element registry
+pub fn reset_affinities() {
+    ELEMENT_AFFINITIES.with(|affinities| *affinities.borrow_mut() = ElementAffinities::default());
+}
+
+/// Extra opinion change a receiver feels because of the sender's element
+pub fn opinion_modifier(affinity: i32) -> i32 {
+    affinity / AFFINITY_PER_OPINION_POINT
+}
+
+/// Base chance (0.0-0.5) that a sender picks a fight because of an elemental clash
+/// Only negative affinities make confrontations more likely
+pub fn confrontation_chance(affinity: i32) -> f64 {
+    if affinity < 0 {
+        (-affinity) as f64 / 200.0
+    } else {
+        0.0
+    }
+}
+
+/// Chance that a sender acts on an elemental clash, from its base chance, the sender's aggression
+/// and the sender's opinion of the receiver
+/// Warmth damps the chance, and dragons who are friends never provoke each other
+pub fn provocation_chance(confrontation_chance: f64, aggression: u32, existing_opinion: i32) -> f64 {
+    if existing_opinion >= FRIENDSHIP_OPINION {
+        return 0.0;
+    }
+    let warmth = existing_opinion.max(0) as f64 / FRIENDSHIP_OPINION as f64;
+    (confrontation_chance * aggression as f64 / 50.0 * (1.0 - warmth)).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_affinity_shifts_opinion_and_sparks_confrontation() {
+        let cases = [
+            (-100, -10, 0.5),
+            (-40, -4, 0.2),
+            (-20, -2, 0.1),
+            (-10, -1, 0.05),
+            (0, 0, 0.0),
+            (20, 2, 0.0),
+            (60, 6, 0.0),
+            (100, 10, 0.0),
+        ];
+        for (affinity, modifier, chance) in cases {
+            assert_eq!(opinion_modifier(affinity), modifier, "modifier for {}", affinity);
+            assert_eq!(confrontation_chance(affinity), chance, "chance for {}", affinity);
+        }
+    }
+
+    #[test]
+    fn test_friends_are_not_provoked_by_elemental_clashes() {
+        assert_eq!(provocation_chance(0.5, 100, -30), 1.0);
+        assert_eq!(provocation_chance(0.5, 50, 0), 0.5);
+        assert_eq!(provocation_chance(0.5, 50, 25), 0.25);
+        assert_eq!(provocation_chance(0.5, 100, FRIENDSHIP_OPINION), 0.0);
+        assert_eq!(provocation_chance(0.0, 100, -80), 0.0);
+    }
+
+    #[test]
+    fn test_loading_an_element_keeps_other_tuned_pairs() {
+        set_affinity("Fire", "Water", 77);
+        set_affinity("Ice", "Fire", 55);
+        let names = element::load_elements(
+            r#"{"elements": [{"name": "Shadow", "affinities": {"Fire": -30}},
+                             {"name": "Ice", "affinities": {"Shadow": 10}}]}"#,
+        )
+        .unwrap();
+        refresh_elements(&names);
+
+        assert_eq!(get_affinity("Fire", "Water"), 77);
+        assert_eq!(get_affinity("Shadow", "Fire"), -30);
+        assert_eq!(get_affinity("Ice", "Shadow"), 10);
+        // Ice was replaced, so its tuned pair is taken from the new definition
+        assert_eq!(get_affinity("Ice", "Fire"), 0);
+
+        element::reset_elements();
+        reset_affinities();
+        assert_eq!(get_affinity("Shadow", "Fire"), 0);
+        assert_ne!(get_affinity("Fire", "Water"), 77);
+    }
+}
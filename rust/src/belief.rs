@@ -0,0 +1,178 @@
+use crate::communication::{conflicting_value, Communication, CommunicationTone, CommunicationType};
+use std::collections::HashMap;
+
+/// Estimate assumed for a value or trait that has never been observed
+const UNKNOWN_ESTIMATE: f64 = 50.0;
+
+/// Observed evidence that a value or trait is strongly held
+const HIGH_EVIDENCE: f64 = 85.0;
+
+/// Observed evidence that a value or trait is weakly held
+const LOW_EVIDENCE: f64 = 20.0;
+
+/// What one dragon has inferred about a single value or trait of another
+#[derive(Debug, Clone, Copy)]
+pub struct Belief {
+    /// Estimated score (0-100)
+    pub estimate: f64,
+    /// How many observations the estimate is based on
+    pub observations: u32,
+}
+
+impl Belief {
+    /// Fold an observation into the estimate as a running average
+    fn observe(&mut self, evidence: f64) {
+        self.observations += 1;
+        self.estimate += (evidence - self.estimate) / self.observations as f64;
+    }
+}
+
+/// A dragon's model of another dragon's mind, built only from what it has seen and heard
+#[derive(Debug, Clone, Default)]
+pub struct Beliefs {
+    values: HashMap<String, Belief>,
+    traits: HashMap<String, Belief>,
+}
+
+impl Beliefs {
+    /// Estimated score for a value, 50 if it has never been observed
+    pub fn value_estimate(&self, value_name: &str) -> f64 {
+        self.values.get(value_name).map(|b| b.estimate).unwrap_or(UNKNOWN_ESTIMATE)
+    }
+
+    /// Estimated score for a trait, 50 if it has never been observed
+    pub fn trait_estimate(&self, trait_name: &str) -> f64 {
+        self.traits.get(trait_name).map(|b| b.estimate).unwrap_or(UNKNOWN_ESTIMATE)
+    }
+
+    /// Whether the value has been observed at least once
+    pub fn knows_value(&self, value_name: &str) -> bool {
+        self.values.contains_key(value_name)
+    }
+
+    pub fn values(&self) -> &HashMap<String, Belief> {
+        &self.values
+    }
+
+    pub fn traits(&self) -> &HashMap<String, Belief> {
+        &self.traits
+    }
+
+    fn observe_value(&mut self, value_name: &str, evidence: f64) {
+        self.values
+            .entry(value_name.to_string())
+            .or_insert(Belief { estimate: UNKNOWN_ESTIMATE, observations: 0 })
+            .observe(evidence);
+    }
+
+    fn observe_trait(&mut self, trait_name: &str, evidence: f64) {
+        self.traits
+            .entry(trait_name.to_string())
+            .or_insert(Belief { estimate: UNKNOWN_ESTIMATE, observations: 0 })
+            .observe(evidence);
+    }
+
+    /// Learn about the sender from a communication they sent
+    /// Dragons speak about what they care about and act according to their traits
+    pub fn observe_communication(&mut self, communication: &Communication) {
+        if let Some(ref value_name) = communication.primary_value {
+            self.observe_value(value_name, HIGH_EVIDENCE);
+        }
+
+        match communication.comm_type {
+            CommunicationType::Playful => self.observe_trait("playfulness", HIGH_EVIDENCE),
+            CommunicationType::Friendly => self.observe_trait("friendliness", HIGH_EVIDENCE),
+            CommunicationType::Curious => self.observe_trait("curiosity", HIGH_EVIDENCE),
+            CommunicationType::Shy => self.observe_trait("sociability", LOW_EVIDENCE),
+            CommunicationType::Confrontational => self.observe_trait("aggression", HIGH_EVIDENCE),
+            CommunicationType::ValueAlignment | CommunicationType::Neutral => {}
+        }
+    }
+
+    /// Learn about the receiver from how they responded to a communication
+    pub fn observe_response(&mut self, communication: &Communication, tone: CommunicationTone) {
+        let warm = matches!(tone, CommunicationTone::Positive | CommunicationTone::Warm);
+        let hostile = matches!(tone, CommunicationTone::Negative | CommunicationTone::Challenging);
+
+        if let Some(ref value_name) = communication.primary_value {
+            if warm {
+                self.observe_value(value_name, HIGH_EVIDENCE);
+            } else if hostile {
+                // Disagreement means the receiver holds the opposing value
+                self.observe_value(value_name, LOW_EVIDENCE);
+                if let Some(opposite) = conflicting_value(value_name) {
+                    self.observe_value(opposite, HIGH_EVIDENCE);
+                }
+            } else {
+                self.observe_value(value_name, LOW_EVIDENCE + 10.0);
+            }
+        }
+
+        let trait_name = match communication.comm_type {
+            CommunicationType::Playful => Some("playfulness"),
+            CommunicationType::Friendly => Some("friendliness"),
+            CommunicationType::Curious => Some("curiosity"),
+            CommunicationType::Confrontational => Some("aggression"),
+            _ => None,
+        };
+        if let Some(trait_name) = trait_name {
+            let evidence = match (communication.comm_type, tone) {
+                // Rising to a challenge shows aggression, withdrawing shows patience
+                (CommunicationType::Confrontational, CommunicationTone::Challenging) => HIGH_EVIDENCE,
+                (CommunicationType::Confrontational, _) => LOW_EVIDENCE,
+                (_, CommunicationTone::Positive | CommunicationTone::Warm) => HIGH_EVIDENCE,
+                _ => LOW_EVIDENCE,
+            };
+            self.observe_trait(trait_name, evidence);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::character::CharacterTraits;
+    use crate::communication::choose_value_to_express;
+
+    #[test]
+    fn test_rejection_reveals_opposing_value() {
+        let communication = Communication::new(CommunicationType::ValueAlignment, String::new(), 70)
+            .with_value("freedom".to_string());
+
+        let mut beliefs = Beliefs::default();
+        beliefs.observe_response(&communication, CommunicationTone::Negative);
+
+        assert!(beliefs.value_estimate("freedom") < 50.0);
+        assert!(beliefs.value_estimate("community") > 50.0);
+        assert!(!beliefs.knows_value("honor"));
+    }
+
+    #[test]
+    fn test_known_opposition_steers_which_value_is_expressed() {
+        let rejected = Communication::new(CommunicationType::ValueAlignment, String::new(), 70)
+            .with_value("freedom".to_string());
+        let mut beliefs = Beliefs::default();
+        beliefs.observe_response(&rejected, CommunicationTone::Negative);
+
+        let top_values = vec![("freedom".to_string(), 90), ("honor".to_string(), 80)];
+        let traits = |aggression_vs_patience| CharacterTraits {
+            friendliness: 50,
+            sociability: 50,
+            curiosity: 50,
+            playfulness: 50,
+            dominance: 50,
+            aggression_vs_patience,
+        };
+
+        // Without beliefs the strongest value is expressed
+        assert_eq!(choose_value_to_express(&top_values, &traits(20), 0, None), Some("freedom"));
+
+        // A patient dragon steers clear of what the listener opposes
+        assert_eq!(choose_value_to_express(&top_values, &traits(20), 0, Some(&beliefs)), Some("honor"));
+        assert_eq!(choose_value_to_express(&top_values[..1], &traits(20), 0, Some(&beliefs)), None);
+
+        // An aggressive dragon that dislikes the listener raises it on purpose
+        assert_eq!(choose_value_to_express(&top_values, &traits(80), -40, Some(&beliefs)), Some("freedom"));
+        assert_eq!(choose_value_to_express(&top_values, &traits(80), 0, Some(&beliefs)), Some("honor"));
+    }
+}
@@ -0,0 +1,304 @@
+use crate::communication::{CommunicationTone, CommunicationType};
+use crate::locale;
+
+/// Minimum age for a dragon to court a mate
+pub const ADULT_AGE: u32 = 5;
+
+/// Minimum age difference between a mentor and their apprentice
+const MENTOR_AGE_GAP: u32 = 5;
+
+/// Warm exchanges needed before two adults become mates
+const COURTSHIPS_FOR_MATES: u32 = 5;
+
+/// Minimum mutual opinion for a courtship to end in a mate bond
+const MATE_OPINION: i32 = 60;
+
+/// Lessons an elder must give before becoming a mentor
+const LESSONS_FOR_MENTORSHIP: u32 = 3;
+
+/// Minimum respect the apprentice must have for the elder
+const MENTOR_RESPECT: i32 = 20;
+
+/// Challenges met head-on before two dragons become sworn rivals
+const DUELS_FOR_RIVALRY: u32 = 3;
+
+/// Kind of bond one dragon has with another, from the holder's perspective
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BondType {
+    /// The other dragon is this dragon's mate
+    Mate,
+    /// The dragons share a parent
+    Sibling,
+    /// The other dragon is this dragon's mentor
+    Mentor,
+    /// The other dragon is this dragon's apprentice
+    Apprentice,
+    /// The dragons have dueled too often to ever be at peace
+    SwornRival,
+}
+
+impl BondType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BondType::Mate => "mate",
+            BondType::Sibling => "sibling",
+            BondType::Mentor => "mentor",
+            BondType::Apprentice => "apprentice",
+            BondType::SwornRival => "sworn rival",
+        }
+    }
+
+    /// The bond's name in the current locale, e.g. "sworn rival"
+    pub fn display_name(&self) -> String {
+        locale::name("bond", &self.as_str().replace(' ', "_"))
+    }
+
+    /// The bond as seen from the other dragon's side
+    pub fn reciprocal(&self) -> BondType {
+        match self {
+            BondType::Mentor => BondType::Apprentice,
+            BondType::Apprentice => BondType::Mentor,
+            other => *other,
+        }
+    }
+
+    /// Whether the bond keeps opinions from fading between contacts
+    /// Mates, siblings, mentors and apprentices stay close; a sworn rivalry is no reason to keep caring
+    pub fn slows_decay(&self) -> bool {
+        !matches!(self, BondType::SwornRival)
+    }
+
+    /// Adjust how much a communication from the bonded dragon moves the receiver's opinion
+    /// Mates and siblings forgive, apprentices listen to their mentors, sworn rivals hold grudges
+    pub fn weigh_opinion_change(&self, comm_type: CommunicationType, change: i32) -> i32 {
+        match self {
+            BondType::Mate => {
+                if change >= 0 {
+                    change * 3 / 2
+                } else {
+                    change / 2
+                }
+            }
+            BondType::Sibling => {
+                if change >= 0 {
+                    change
+                } else {
+                    change / 2
+                }
+            }
+            BondType::Mentor => match comm_type {
+                CommunicationType::ValueAlignment | CommunicationType::Curious => change * 3 / 2,
+                _ => change,
+            },
+            BondType::Apprentice => change,
+            BondType::SwornRival => {
+                if change >= 0 {
+                    change / 2
+                } else if comm_type == CommunicationType::Confrontational {
+                    change * 3 / 2
+                } else {
+                    change
+                }
+            }
+        }
+    }
+}
+
+/// A bond one dragon holds with another
+#[derive(Debug, Clone)]
+pub struct Bond {
+    /// Name of the other dragon
+    pub other_name: String,
+    pub bond_type: BondType,
+    /// Clan day on which the bond formed
+    pub formed_day: u32,
+}
+
+/// Counts of the events that lead to bonds between a pair of dragons
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BondProgress {
+    /// Warm exchanges between two adults
+    pub courtships: u32,
+    /// Lessons given by the elder of the pair
+    pub lessons: u32,
+    /// Confrontations answered with a challenge
+    pub duels: u32,
+}
+
+/// What is known about one side of an exchange when checking for new bonds
+pub struct BondCandidate<'a> {
+    pub age: u32,
+    pub opinion: i32,
+    pub respect: i32,
+    pub has_mate: bool,
+    /// Whether this dragon hatched the other one
+    pub is_parent: bool,
+    pub bonds: &'a [BondType],
+}
+
+impl BondProgress {
+    /// Record an exchange where the sender sent a communication of comm_type and got the given tone back
+    pub fn record_exchange(
+        &mut self,
+        comm_type: CommunicationType,
+        tone: CommunicationTone,
+        sender_age: u32,
+        receiver_age: u32,
+    ) {
+        let positive = matches!(tone, CommunicationTone::Positive | CommunicationTone::Warm);
+
+        if comm_type == CommunicationType::Confrontational && tone == CommunicationTone::Challenging {
+            self.duels += 1;
+        }
+
+        if positive
+            && sender_age >= ADULT_AGE
+            && receiver_age >= ADULT_AGE
+            && matches!(
+                comm_type,
+                CommunicationType::Friendly | CommunicationType::Playful | CommunicationType::ValueAlignment
+            )
+        {
+            self.courtships += 1;
+        }
+
+        if positive
+            && sender_age >= receiver_age + MENTOR_AGE_GAP
+            && matches!(comm_type, CommunicationType::ValueAlignment | CommunicationType::Curious)
+        {
+            self.lessons += 1;
+        }
+    }
+
+    /// Check whether the pair has earned a new bond
+    /// Returns the bond from the sender's perspective
+    pub fn check_new_bond(&self, sender: &BondCandidate, receiver: &BondCandidate) -> Option<BondType> {
+        if self.duels >= DUELS_FOR_RIVALRY && !sender.bonds.contains(&BondType::SwornRival) {
+            return Some(BondType::SwornRival);
+        }
+
+        if self.courtships >= COURTSHIPS_FOR_MATES
+            && !sender.has_mate
+            && !receiver.has_mate
+            && !sender.is_parent
+            && !receiver.is_parent
+            && !sender.bonds.contains(&BondType::Sibling)
+            && !receiver.bonds.contains(&BondType::Sibling)
+            && sender.opinion >= MATE_OPINION
+            && receiver.opinion >= MATE_OPINION
+        {
+            return Some(BondType::Mate);
+        }
+
+        if self.lessons >= LESSONS_FOR_MENTORSHIP
+            && sender.age >= receiver.age + MENTOR_AGE_GAP
+            && receiver.respect >= MENTOR_RESPECT
+            && !sender.bonds.contains(&BondType::Apprentice)
+        {
+            return Some(BondType::Apprentice);
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(age: u32, opinion: i32, respect: i32, bonds: &[BondType]) -> BondCandidate<'_> {
+        BondCandidate {
+            age,
+            opinion,
+            respect,
+            has_mate: bonds.contains(&BondType::Mate),
+            is_parent: false,
+            bonds,
+        }
+    }
+
+    fn exchange(
+        progress: &mut BondProgress,
+        times: u32,
+        comm_type: CommunicationType,
+        tone: CommunicationTone,
+        ages: (u32, u32),
+    ) {
+        for _ in 0..times {
+            progress.record_exchange(comm_type, tone, ages.0, ages.1);
+        }
+    }
+
+    #[test]
+    fn test_exchanges_earn_mate_mentor_and_rival_bonds() {
+        use CommunicationTone::{Challenging, Positive, Warm};
+        use CommunicationType::{Confrontational, Curious, Friendly, Playful};
+
+        // Courtship between adults who think highly of each other
+        let mut courtship = BondProgress::default();
+        exchange(&mut courtship, COURTSHIPS_FOR_MATES - 1, Friendly, Warm, (6, 7));
+        let fond = candidate(6, MATE_OPINION, 0, &[]);
+        assert_eq!(courtship.check_new_bond(&fond, &fond), None);
+        exchange(&mut courtship, 1, Playful, Positive, (6, 7));
+        assert_eq!(courtship.check_new_bond(&fond, &fond), Some(BondType::Mate));
+        let sibling = candidate(6, MATE_OPINION, 0, &[BondType::Sibling]);
+        assert_eq!(courtship.check_new_bond(&sibling, &fond), None);
+        let taken = candidate(7, MATE_OPINION, 0, &[BondType::Mate]);
+        assert_eq!(courtship.check_new_bond(&fond, &taken), None);
+
+        // Kin never become mates, whichever of them holds the bond or hatched the other
+        let parent = BondCandidate {
+            is_parent: true,
+            ..candidate(12, MATE_OPINION, 0, &[])
+        };
+        let kin_cases = [(&sibling, &fond), (&fond, &sibling), (&parent, &fond), (&fond, &parent)];
+        for (sender, receiver) in kin_cases {
+            assert_eq!(courtship.check_new_bond(sender, receiver), None);
+        }
+
+        // Hatchlings never court
+        let mut young = BondProgress::default();
+        exchange(&mut young, COURTSHIPS_FOR_MATES, Friendly, Warm, (2, 7));
+        assert_eq!(young.courtships, 0);
+
+        // Lessons from an elder to a respectful apprentice
+        let mut mentorship = BondProgress::default();
+        exchange(&mut mentorship, LESSONS_FOR_MENTORSHIP, Curious, Positive, (12, 3));
+        let elder = candidate(12, 0, 0, &[]);
+        let apprentice = candidate(3, 0, MENTOR_RESPECT, &[]);
+        assert_eq!(mentorship.check_new_bond(&elder, &apprentice), Some(BondType::Apprentice));
+        assert_eq!(mentorship.check_new_bond(&elder, &candidate(3, 0, MENTOR_RESPECT - 1, &[])), None);
+        let already_teaching = candidate(12, 0, 0, &[BondType::Apprentice]);
+        assert_eq!(mentorship.check_new_bond(&already_teaching, &apprentice), None);
+        assert_eq!(BondType::Apprentice.reciprocal(), BondType::Mentor);
+
+        // Challenges met head-on, which outweigh any fondness
+        let mut duels = BondProgress::default();
+        exchange(&mut duels, DUELS_FOR_RIVALRY, Confrontational, Challenging, (6, 7));
+        exchange(&mut duels, COURTSHIPS_FOR_MATES, Friendly, Warm, (6, 7));
+        assert_eq!(duels.check_new_bond(&fond, &fond), Some(BondType::SwornRival));
+        let sworn = candidate(6, MATE_OPINION, 0, &[BondType::SwornRival]);
+        assert_eq!(duels.check_new_bond(&sworn, &fond), Some(BondType::Mate));
+    }
+
+    #[test]
+    fn test_bonds_weigh_opinion_changes() {
+        use CommunicationType::{Confrontational, Curious, Friendly};
+        let cases = [
+            (BondType::Mate, Friendly, 10, 15),
+            (BondType::Mate, Confrontational, -10, -5),
+            (BondType::Sibling, Friendly, 10, 10),
+            (BondType::Sibling, Confrontational, -10, -5),
+            (BondType::Mentor, Curious, 10, 15),
+            (BondType::Mentor, Friendly, 10, 10),
+            (BondType::Apprentice, Curious, 10, 10),
+            (BondType::SwornRival, Friendly, 10, 5),
+            (BondType::SwornRival, Confrontational, -10, -15),
+            (BondType::SwornRival, Friendly, -10, -10),
+        ];
+        for (bond, comm_type, change, expected) in cases {
+            let weighed = bond.weigh_opinion_change(comm_type, change);
+            assert_eq!(weighed, expected, "{:?} {:?} {}", bond, comm_type, change);
+        }
+    }
+}
@@ -1,6 +1,12 @@
-use crate::dragon::DragonElement;
+use crate::element;
+use crate::locale;
 use crate::values::{DragonValues, generate_dragon_values};
 
+/// Every trait name a dragon's traits can be adjusted by, including both ends of an axis
+pub const TRAIT_NAMES: [&str; 7] = [
+    "friendliness", "sociability", "curiosity", "playfulness", "dominance", "aggression", "patience",
+];
+
 #[derive(Debug, Clone, Copy)]
 pub struct CharacterTraits {
     // Non-conflicting individual traits (0-100)
@@ -49,6 +55,49 @@ pub struct CharacterTraits {
 pub struct DragonCharacter {
     pub traits: CharacterTraits,
     pub values: DragonValues,
+    development: TraitDevelopment,
+}
+
+/// How a dragon's traits develop with age and experience
+#[derive(Debug, Clone, Copy)]
+pub struct TraitDevelopmentConfig {
+    /// Rejected communications needed to lose a point of sociability
+    pub rejections_per_point: u32,
+    /// Successful attempts at leading needed to gain a point of dominance
+    pub leadership_per_point: u32,
+    /// Patience gained each year of age
+    pub patience_per_year: u32,
+    /// Furthest any trait can develop away from where it started
+    pub max_change: u32,
+}
+
+impl Default for TraitDevelopmentConfig {
+    fn default() -> Self {
+        TraitDevelopmentConfig {
+            rejections_per_point: 3,
+            leadership_per_point: 3,
+            patience_per_year: 1,
+            max_change: 25,
+        }
+    }
+}
+
+/// Experience a dragon has gathered towards developing its traits
+#[derive(Debug, Clone)]
+struct TraitDevelopment {
+    /// Traits the dragon started with, which development is bounded around
+    baseline: CharacterTraits,
+    rejections: u32,
+    leadership_successes: u32,
+}
+
+impl TraitDevelopment {
+    /// Move a trait by delta without leaving 0-100 or straying further than max_change from its baseline
+    fn develop(trait_value: &mut u32, baseline: u32, delta: i32, max_change: u32) {
+        let low = baseline.saturating_sub(max_change) as i32;
+        let high = baseline.saturating_add(max_change).min(100) as i32;
+        *trait_value = (*trait_value as i32 + delta).clamp(low, high) as u32;
+    }
 }
 
 impl CharacterTraits {
@@ -70,6 +119,24 @@ impl CharacterTraits {
     pub fn patience(&self) -> u32 {
         Self::get_second_from_axis(self.aggression_vs_patience)
     }
+
+    /// Move a trait by delta, clamped to 0-100
+    /// For traits on an axis this moves the axis, so raising patience lowers aggression
+    pub fn adjust(&mut self, name: &str, delta: i32) {
+        fn shift(score: &mut u32, delta: i32) {
+            *score = (*score as i32 + delta).clamp(0, 100) as u32;
+        }
+        match name {
+            "friendliness" => shift(&mut self.friendliness, delta),
+            "sociability" => shift(&mut self.sociability, delta),
+            "curiosity" => shift(&mut self.curiosity, delta),
+            "playfulness" => shift(&mut self.playfulness, delta),
+            "dominance" => shift(&mut self.dominance, delta),
+            "aggression" => shift(&mut self.aggression_vs_patience, delta),
+            "patience" => shift(&mut self.aggression_vs_patience, -delta),
+            _ => {}
+        }
+    }
 }
 
 impl DragonCharacter {
@@ -77,32 +144,81 @@ impl DragonCharacter {
         traits: Option<CharacterTraits>,
         values: Option<DragonValues>,
     ) -> Self {
+        let traits = traits.unwrap_or_else(|| CharacterTraits {
+            friendliness: random_trait(),
+            sociability: random_trait(),
+            curiosity: random_trait(),
+            playfulness: random_trait(),
+            dominance: random_trait(),
+            aggression_vs_patience: random_trait(),
+        });
         DragonCharacter {
-            traits: traits.unwrap_or_else(|| CharacterTraits {
-                friendliness: random_trait(),
-                sociability: random_trait(),
-                curiosity: random_trait(),
-                playfulness: random_trait(),
-                dominance: random_trait(),
-                aggression_vs_patience: random_trait(),
-            }),
-            values: values.unwrap_or_else(|| generate_dragon_values(None)),
+            traits,
+            values: values.unwrap_or_else(|| generate_dragon_values(None, None)),
+            development: TraitDevelopment {
+                baseline: traits,
+                rejections: 0,
+                leadership_successes: 0,
+            },
+        }
+    }
+
+    /// A communication this dragon sent was rebuffed
+    /// Repeated rejection makes a dragon less sociable
+    pub fn record_rejection(&mut self, config: &TraitDevelopmentConfig) {
+        self.development.rejections += 1;
+        if config.rejections_per_point > 0 && self.development.rejections.is_multiple_of(config.rejections_per_point) {
+            TraitDevelopment::develop(
+                &mut self.traits.sociability,
+                self.development.baseline.sociability,
+                -1,
+                config.max_change,
+            );
         }
     }
 
+    /// This dragon took the lead with a less dominant dragon and was well received
+    /// Successful leadership makes a dragon more dominant
+    pub fn record_leadership(&mut self, config: &TraitDevelopmentConfig) {
+        self.development.leadership_successes += 1;
+        if config.leadership_per_point > 0 && self.development.leadership_successes.is_multiple_of(config.leadership_per_point) {
+            TraitDevelopment::develop(
+                &mut self.traits.dominance,
+                self.development.baseline.dominance,
+                1,
+                config.max_change,
+            );
+        }
+    }
+
+    /// A year has passed; dragons grow more patient with age
+    pub fn age_one_year(&mut self, config: &TraitDevelopmentConfig) {
+        TraitDevelopment::develop(
+            &mut self.traits.aggression_vs_patience,
+            self.development.baseline.aggression_vs_patience,
+            -(config.patience_per_year.min(100) as i32),
+            config.max_change,
+        );
+    }
+
     pub fn get_interaction_style(&self) -> String {
+        locale::name("style", self.interaction_style())
+    }
+
+    /// Label of get_interaction_style that doesn't depend on the locale, e.g. "playful"
+    pub fn interaction_style(&self) -> &'static str {
         if self.traits.aggression() > 70 {
-            "aggressive".to_string()
+            "aggressive"
         } else if self.traits.friendliness > 70 && self.traits.playfulness > 60 {
-            "playful".to_string()
+            "playful"
         } else if self.traits.friendliness > 70 {
-            "friendly".to_string()
+            "friendly"
         } else if self.traits.sociability < 30 {
-            "shy".to_string()
+            "shy"
         } else if self.traits.curiosity > 70 {
-            "curious".to_string()
+            "curious"
         } else {
-            "serious".to_string()
+            "serious"
         }
     }
 
@@ -123,7 +239,8 @@ fn random_trait() -> u32 {
     ((value1 + value2) / 2) as u32
 }
 
-pub fn generate_random_character(element: Option<DragonElement>) -> DragonCharacter {
+/// Generate a random character nudged by a dragon's element, and at reduced weight by its secondary element
+pub fn generate_random_character(element: Option<&str>, secondary_element: Option<&str>) -> DragonCharacter {
     // Generate random axis values (0-100)
     // For axes: higher = first trait, lower = second trait
     let mut traits = CharacterTraits {
@@ -135,42 +252,13 @@ pub fn generate_random_character(element: Option<DragonElement>) -> DragonCharac
         aggression_vs_patience: random_trait(),
     };
 
-    let values = generate_dragon_values(element);
+    let values = generate_dragon_values(element, secondary_element);
 
-    // Element-based character adjustments
+    // Element-based character adjustments from the element registry
     // Adjust axes by moving toward the preferred trait
     if let Some(element) = element {
-        match element {
-            DragonElement::Fire => {
-                // Higher aggression, higher dominance
-                traits.aggression_vs_patience = (traits.aggression_vs_patience + 20).min(100);
-                traits.dominance = (traits.dominance + 15).min(100);
-            }
-            DragonElement::Water => {
-                // Higher patience, higher friendliness
-                traits.aggression_vs_patience = traits.aggression_vs_patience.saturating_sub(20);
-                traits.friendliness = (traits.friendliness + 15).min(100);
-            }
-            DragonElement::Earth => {
-                // Higher patience, lower curiosity
-                traits.aggression_vs_patience = traits.aggression_vs_patience.saturating_sub(25);
-                traits.curiosity = traits.curiosity.saturating_sub(15);
-            }
-            DragonElement::Wind => {
-                // Higher curiosity, higher playfulness
-                traits.curiosity = (traits.curiosity + 20).min(100);
-                traits.playfulness = (traits.playfulness + 15).min(100);
-            }
-            DragonElement::Lightning => {
-                // Higher aggression, higher curiosity
-                traits.aggression_vs_patience = (traits.aggression_vs_patience + 15).min(100);
-                traits.curiosity = (traits.curiosity + 20).min(100);
-            }
-            DragonElement::Ice => {
-                // Lower sociability, higher patience
-                traits.sociability = traits.sociability.saturating_sub(20);
-                traits.aggression_vs_patience = traits.aggression_vs_patience.saturating_sub(25);
-            }
+        for (trait_name, delta) in element::blended_trait_nudges(element, secondary_element) {
+            traits.adjust(&trait_name, delta);
         }
     }
 
@@ -184,3 +272,73 @@ pub fn generate_random_character(element: Option<DragonElement>) -> DragonCharac
 
     DragonCharacter::new(Some(traits), Some(values))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn steady_character() -> DragonCharacter {
+        let traits = CharacterTraits {
+            friendliness: 50,
+            sociability: 50,
+            curiosity: 50,
+            playfulness: 50,
+            dominance: 50,
+            aggression_vs_patience: 90,
+        };
+        DragonCharacter::new(Some(traits), None)
+    }
+
+    #[test]
+    fn test_experience_and_age_develop_traits_within_bounds() {
+        let config = TraitDevelopmentConfig {
+            rejections_per_point: 2,
+            leadership_per_point: 3,
+            patience_per_year: 4,
+            max_change: 10,
+        };
+        let mut character = steady_character();
+
+        character.record_rejection(&config);
+        assert_eq!(character.traits().sociability, 50);
+        character.record_rejection(&config);
+        assert_eq!(character.traits().sociability, 49);
+        (0..40).for_each(|_| character.record_rejection(&config));
+        assert_eq!(character.traits().sociability, 40);
+
+        (0..5).for_each(|_| character.record_leadership(&config));
+        assert_eq!(character.traits().dominance, 51);
+
+        character.age_one_year(&config);
+        assert_eq!(character.traits().patience(), 14);
+        (0..10).for_each(|_| character.age_one_year(&config));
+        assert_eq!(character.traits().patience(), 20);
+
+        // Zero rates switch development off rather than dividing by zero
+        let off = TraitDevelopmentConfig {
+            rejections_per_point: 0,
+            leadership_per_point: 0,
+            patience_per_year: 0,
+            max_change: 10,
+        };
+        character.record_rejection(&off);
+        character.record_leadership(&off);
+        character.age_one_year(&off);
+        assert_eq!((character.traits().sociability, character.traits().dominance), (40, 51));
+    }
+
+    #[test]
+    fn test_unbounded_development_stays_within_range() {
+        let config = TraitDevelopmentConfig {
+            rejections_per_point: 1,
+            leadership_per_point: 1,
+            patience_per_year: u32::MAX,
+            max_change: u32::MAX,
+        };
+        let mut character = steady_character();
+        (0..60).for_each(|_| character.record_leadership(&config));
+        assert_eq!(character.traits().dominance, 100);
+        character.age_one_year(&config);
+        assert_eq!(character.traits().patience(), 100);
+    }
+}
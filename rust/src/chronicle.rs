@@ -0,0 +1,365 @@
+use crate::bond::BondType;
+use crate::clan::DAYS_PER_YEAR;
+use crate::dragon::InteractionResult;
+use crate::locale;
+use std::collections::{HashMap, HashSet};
+
+/// Opinion two dragons must hold of each other before the chronicle calls them friends
+pub const FRIENDSHIP_OPINION: i32 = 50;
+
+/// Seasons of the clan year, in order
+const SEASONS: [&str; 4] = ["spring", "summer", "autumn", "winter"];
+
+/// Kind of noteworthy development in the clan's history
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChronicleEventKind {
+    /// Two dragons became friends for the first time
+    FirstFriendship,
+    /// Two dragons swore rivalry
+    RivalryFormed,
+    /// Two dragons became mates
+    MatesFormed,
+    /// A dragon confronted a dragon it had sworn an oath of honor to
+    PromiseBroken,
+    /// The clan looks to a new leader
+    LeaderChanged,
+    /// A dragon hatched
+    Hatching,
+    /// A dragon left the clan
+    Departure,
+    /// The clan took a new name
+    ClanRenamed,
+}
+
+impl ChronicleEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChronicleEventKind::FirstFriendship => "first friendship",
+            ChronicleEventKind::RivalryFormed => "rivalry formed",
+            ChronicleEventKind::MatesFormed => "mates formed",
+            ChronicleEventKind::PromiseBroken => "promise broken",
+            ChronicleEventKind::LeaderChanged => "leader changed",
+            ChronicleEventKind::Hatching => "hatching",
+            ChronicleEventKind::Departure => "departure",
+            ChronicleEventKind::ClanRenamed => "clan renamed",
+        }
+    }
+}
+
+/// A noteworthy development, naming the dragons involved, or for a renaming the old and new clan names
+/// Names rather than indices are kept so entries survive dragons leaving the clan
+#[derive(Debug, Clone)]
+pub struct ChronicleEvent {
+    pub day: u32,
+    pub kind: ChronicleEventKind,
+    pub dragons: Vec<String>,
+}
+
+impl ChronicleEvent {
+    /// One sentence describing the event, in the current locale
+    pub fn describe(&self) -> String {
+        let key = match self.kind {
+            ChronicleEventKind::FirstFriendship => "chronicle.event.first_friendship",
+            ChronicleEventKind::RivalryFormed => "chronicle.event.rivalry_formed",
+            ChronicleEventKind::MatesFormed => "chronicle.event.mates_formed",
+            ChronicleEventKind::PromiseBroken => "chronicle.event.promise_broken",
+            ChronicleEventKind::LeaderChanged if self.dragons.len() < 2 => "chronicle.event.leader_emerged",
+            ChronicleEventKind::LeaderChanged => "chronicle.event.leader_changed",
+            ChronicleEventKind::Hatching => "chronicle.event.hatching",
+            ChronicleEventKind::Departure => "chronicle.event.departure",
+            ChronicleEventKind::ClanRenamed => "chronicle.event.clan_renamed",
+        };
+        let name = |i: usize| self.dragons.get(i).map(String::as_str).unwrap_or_default();
+        locale::render(key, &[("dragon1", name(0)), ("dragon2", name(1)), ("dragon3", name(2))])
+    }
+}
+
+/// Length of time a chronicle summary covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChroniclePeriod {
+    Day,
+    /// A quarter of a year
+    Season,
+    Year,
+}
+
+impl ChroniclePeriod {
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "day" => Some(ChroniclePeriod::Day),
+            "season" => Some(ChroniclePeriod::Season),
+            "year" => Some(ChroniclePeriod::Year),
+            _ => None,
+        }
+    }
+
+    /// Number of the period that contains day, counting from the clan's first day
+    fn index_of(&self, day: u32) -> u32 {
+        match self {
+            ChroniclePeriod::Day => day,
+            ChroniclePeriod::Season => {
+                let seasons = SEASONS.len() as u32;
+                (day / DAYS_PER_YEAR) * seasons + (day % DAYS_PER_YEAR) * seasons / DAYS_PER_YEAR
+            }
+            ChroniclePeriod::Year => day / DAYS_PER_YEAR,
+        }
+    }
+
+    /// First and last day of a period
+    fn bounds(&self, index: u32) -> (u32, u32) {
+        match self {
+            ChroniclePeriod::Day => (index, index),
+            ChroniclePeriod::Season => {
+                let seasons = SEASONS.len() as u32;
+                let year_start = (index / seasons) * DAYS_PER_YEAR;
+                let season = index % seasons;
+                (
+                    year_start + (season * DAYS_PER_YEAR).div_ceil(seasons),
+                    year_start + ((season + 1) * DAYS_PER_YEAR).div_ceil(seasons) - 1,
+                )
+            }
+            ChroniclePeriod::Year => (index * DAYS_PER_YEAR, (index + 1) * DAYS_PER_YEAR - 1),
+        }
+    }
+
+    /// Name of a period in the current locale, e.g. "Day 12", "Spring of Year 2" or "Year 2"
+    fn label(&self, index: u32) -> String {
+        match self {
+            ChroniclePeriod::Day => locale::render("chronicle.period.day", &[("day", &index.to_string())]),
+            ChroniclePeriod::Season => {
+                let seasons = SEASONS.len() as u32;
+                locale::render(
+                    "chronicle.period.season",
+                    &[
+                        ("season", &locale::name("season", SEASONS[(index % seasons) as usize])),
+                        ("year", &(index / seasons + 1).to_string()),
+                    ],
+                )
+            }
+            ChroniclePeriod::Year => locale::render("chronicle.period.year", &[("year", &(index + 1).to_string())]),
+        }
+    }
+}
+
+/// What happened in the clan over one period
+#[derive(Debug, Clone)]
+pub struct ChronicleSummary {
+    pub label: String,
+    pub start_day: u32,
+    pub end_day: u32,
+    pub interactions: u32,
+    pub events: Vec<ChronicleEvent>,
+    /// Readable account of the period, in the current locale
+    pub narrative: String,
+}
+
+/// The clan's history: how busy each day was and every noteworthy development
+#[derive(Debug, Clone, Default)]
+pub struct Chronicle {
+    events: Vec<ChronicleEvent>,
+    interactions_by_day: HashMap<u32, u32>,
+    /// Pairs that have been friends, by name in sorted order
+    friendships: HashSet<(String, String)>,
+    leader: Option<String>,
+}
+
+impl Chronicle {
+    /// Note an interaction and any development it brought about
+    /// receiver is None for abilities without a target
+    pub fn record_interaction(&mut self, day: u32, sender: &str, receiver: Option<&str>, result: &InteractionResult) {
+        *self.interactions_by_day.entry(day).or_insert(0) += 1;
+        let receiver = match receiver {
+            Some(receiver) => receiver,
+            None => return,
+        };
+
+        if let Some(opinions) = result.opinions() {
+            let pair = if sender < receiver {
+                (sender.to_string(), receiver.to_string())
+            } else {
+                (receiver.to_string(), sender.to_string())
+            };
+            if opinions.sender_after >= FRIENDSHIP_OPINION
+                && opinions.receiver_after >= FRIENDSHIP_OPINION
+                && self.friendships.insert(pair)
+            {
+                self.record(day, ChronicleEventKind::FirstFriendship, &[sender, receiver]);
+            }
+        }
+
+        match result.new_bond() {
+            Some(BondType::SwornRival) => self.record(day, ChronicleEventKind::RivalryFormed, &[sender, receiver]),
+            Some(BondType::Mate) => self.record(day, ChronicleEventKind::MatesFormed, &[sender, receiver]),
+            _ => {}
+        }
+
+        if result.broke_promise() {
+            self.record(day, ChronicleEventKind::PromiseBroken, &[sender, receiver]);
+        }
+    }
+
+    /// Note who leads the clan, recording a change of leader
+    pub fn record_leader(&mut self, day: u32, leader: Option<String>) {
+        if leader == self.leader {
+            return;
+        }
+        if let Some(ref new_leader) = leader {
+            match self.leader {
+                Some(ref old_leader) => {
+                    let old_leader = old_leader.clone();
+                    self.record(day, ChronicleEventKind::LeaderChanged, &[new_leader, &old_leader]);
+                }
+                None => self.record(day, ChronicleEventKind::LeaderChanged, &[new_leader]),
+            }
+        }
+        self.leader = leader;
+    }
+
+    pub fn record_hatching(&mut self, day: u32, hatchling: &str, parent1: &str, parent2: &str) {
+        self.record(day, ChronicleEventKind::Hatching, &[hatchling, parent1, parent2]);
+    }
+
+    pub fn record_departure(&mut self, day: u32, name: &str) {
+        self.record(day, ChronicleEventKind::Departure, &[name]);
+    }
+
+    pub fn record_rename(&mut self, day: u32, old_name: &str, new_name: &str) {
+        self.record(day, ChronicleEventKind::ClanRenamed, &[old_name, new_name]);
+    }
+
+    fn record(&mut self, day: u32, kind: ChronicleEventKind, dragons: &[&str]) {
+        self.events.push(ChronicleEvent {
+            day,
+            kind,
+            dragons: dragons.iter().map(|name| name.to_string()).collect(),
+        });
+    }
+
+    /// Summaries of every period up to and including today, oldest first
+    /// Periods in which nothing happened are left out
+    pub fn summaries(&self, period: ChroniclePeriod, today: u32) -> Vec<ChronicleSummary> {
+        (0..=period.index_of(today))
+            .filter_map(|index| {
+                let (start_day, end_day) = period.bounds(index);
+                let interactions: u32 = (start_day..=end_day)
+                    .filter_map(|day| self.interactions_by_day.get(&day))
+                    .sum();
+                let events: Vec<ChronicleEvent> = self
+                    .events
+                    .iter()
+                    .filter(|e| e.day >= start_day && e.day <= end_day)
+                    .cloned()
+                    .collect();
+                if interactions == 0 && events.is_empty() {
+                    return None;
+                }
+
+                let label = period.label(index);
+                let mut sentences = vec![locale::render_plural(
+                    "chronicle.summary.interactions",
+                    interactions,
+                    &[("period", &label)],
+                )];
+                if events.is_empty() {
+                    sentences.push(locale::render("chronicle.summary.quiet", &[]));
+                }
+                sentences.extend(events.iter().map(ChronicleEvent::describe));
+
+                Some(ChronicleSummary {
+                    label,
+                    start_day,
+                    end_day,
+                    interactions,
+                    events,
+                    narrative: sentences.join(" "),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dragon::OpinionSnapshot;
+
+    fn exchange(sender_after: i32, receiver_after: i32) -> InteractionResult {
+        InteractionResult::new(String::new(), 0).with_opinions(OpinionSnapshot {
+            sender_before: 0,
+            sender_after,
+            receiver_before: 0,
+            receiver_after,
+        })
+    }
+
+    fn kinds(chronicle: &Chronicle) -> Vec<ChronicleEventKind> {
+        chronicle.events.iter().map(|e| e.kind).collect()
+    }
+
+    #[test]
+    fn test_first_friendship_is_recorded_once_per_pair() {
+        let mut chronicle = Chronicle::default();
+
+        // Friendship needs both opinions at the threshold
+        chronicle.record_interaction(1, "Ignis", Some("Aqua"), &exchange(FRIENDSHIP_OPINION, 49));
+        assert!(kinds(&chronicle).is_empty());
+
+        chronicle.record_interaction(2, "Ignis", Some("Aqua"), &exchange(FRIENDSHIP_OPINION, FRIENDSHIP_OPINION));
+        assert_eq!(kinds(&chronicle), vec![ChronicleEventKind::FirstFriendship]);
+        assert_eq!(chronicle.events[0].dragons, vec!["Ignis", "Aqua"]);
+
+        // The same pair in either order is not a first friendship again
+        chronicle.record_interaction(3, "Aqua", Some("Ignis"), &exchange(80, 80));
+        chronicle.record_interaction(3, "Terra", None, &exchange(80, 80));
+        assert_eq!(kinds(&chronicle).len(), 1);
+        assert_eq!(chronicle.interactions_by_day.get(&3), Some(&2));
+    }
+
+    #[test]
+    fn test_bonds_and_broken_promises_are_recorded() {
+        let mut chronicle = Chronicle::default();
+        let rivalry = InteractionResult::new(String::new(), -10).with_new_bond(Some(BondType::SwornRival));
+        let mates = InteractionResult::new(String::new(), 10).with_new_bond(Some(BondType::Mate));
+        let mentorship = InteractionResult::new(String::new(), 10).with_new_bond(Some(BondType::Mentor));
+        let betrayal = InteractionResult::new(String::new(), -10).with_broken_promise(true);
+
+        chronicle.record_interaction(1, "Ignis", Some("Terra"), &rivalry);
+        chronicle.record_interaction(2, "Aqua", Some("Zephyr"), &mates);
+        chronicle.record_interaction(3, "Terra", Some("Aqua"), &mentorship);
+        chronicle.record_interaction(4, "Zephyr", Some("Aqua"), &betrayal);
+        assert_eq!(
+            kinds(&chronicle),
+            vec![
+                ChronicleEventKind::RivalryFormed,
+                ChronicleEventKind::MatesFormed,
+                ChronicleEventKind::PromiseBroken,
+            ]
+        );
+        assert_eq!(chronicle.events[2].dragons, vec!["Zephyr", "Aqua"]);
+    }
+
+    #[test]
+    fn test_seasons_summarize_developments() {
+        let mut chronicle = Chronicle::default();
+        chronicle.record_leader(2, Some("Terra".to_string()));
+        chronicle.record_hatching(9, "Emberis", "Ignis", "Aqua");
+        chronicle.record_leader(10, Some("Ignis".to_string()));
+        chronicle.record_departure(31, "Terra");
+
+        let seasons = chronicle.summaries(ChroniclePeriod::Season, 31);
+        assert_eq!(seasons.len(), 3);
+        assert_eq!((seasons[0].start_day, seasons[0].end_day), (0, 7));
+        assert_eq!((seasons[1].start_day, seasons[1].end_day), (8, 14));
+        assert_eq!(seasons[1].label, "Summer of Year 1");
+        assert_eq!(seasons[1].events.len(), 2);
+        assert!(seasons[1].narrative.contains("Leadership of the clan passed from Terra to Ignis."));
+        assert_eq!(seasons[2].label, "Spring of Year 2");
+
+        let years = chronicle.summaries(ChroniclePeriod::Year, 31);
+        assert_eq!(years.len(), 2);
+        assert_eq!(years[0].events.len(), 3);
+        assert_eq!(years[1].events[0].kind, ChronicleEventKind::Departure);
+        assert_eq!(ChroniclePeriod::Season.bounds(3), (23, 29));
+    }
+}
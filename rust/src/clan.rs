@@ -1,11 +1,37 @@
 use wasm_bindgen::prelude::*;
-use crate::dragon::{Dragon, InteractionResult};
+use crate::ability::{self, ClanState, ABILITY_CHANCE};
+use crate::belief::Beliefs;
+use crate::bond::{Bond, BondCandidate, BondType};
+use crate::character::TraitDevelopmentConfig;
+use crate::chronicle::Chronicle;
+use crate::clan_name::{ClanComposition, ClanTitle};
+use crate::communication::Communication;
+use crate::dragon::{Dragon, InteractionResult, OpinionSnapshot};
+use crate::element::SECONDARY_ELEMENT_WEIGHT;
+use crate::interaction_log::{InteractionLog, LogEntry};
+use crate::faction::{self, Faction, FactionEvent, Factions, FACTION_PARTNER_WEIGHT};
+use crate::memory::Memory;
+use crate::name_generator::{self, NameMethod};
+use crate::name_style::{self, DEFAULT_NAME_STYLE};
+use crate::network::SocialGraph;
+use crate::recorder::{ClanMetrics, Recorder, RecorderConfig};
+use crate::relationship::{OpinionDecay, Relationship};
+use crate::stats::{self, Cohesion, CommunicationTally};
+use crate::values::{ValueShift, VALUE_NAMES};
+use std::collections::HashMap;
+
+/// Number of interactions that make up one day on the clan clock
+pub const INTERACTIONS_PER_DAY: u32 = 10;
+
+/// Number of days after which every dragon grows a year older
+pub const DAYS_PER_YEAR: u32 = 30;
 
 /// Interaction result with dragon indices
 /// This is used internally and not exposed to WASM
 pub struct InteractionWithIndices {
     pub dragon1_idx: usize,
-    pub dragon2_idx: usize,
+    /// None for abilities that don't target another dragon
+    pub dragon2_idx: Option<usize>,
     pub result: InteractionResult,
 }
 
@@ -13,6 +39,23 @@ pub struct InteractionWithIndices {
 pub struct DragonClan {
     name: String,
     dragons: Vec<Dragon>,
+    day: u32,
+    interactions_today: u32,
+    opinion_decay: OpinionDecay,
+    trait_development: TraitDevelopmentConfig,
+    state: ClanState,
+    chronicle: Chronicle,
+    name_method: NameMethod,
+    /// Name style pack used for hatchlings and the clan's own name
+    name_style: String,
+    /// Element and value the clan's name was drawn from, if it was named after its composition
+    title: Option<ClanTitle>,
+    /// Whether the clan takes a new name when its composition shifts
+    auto_rename: bool,
+    communications: CommunicationTally,
+    factions: Factions,
+    recorder: Recorder,
+    log: InteractionLog,
 }
 
 #[wasm_bindgen]
@@ -22,6 +65,20 @@ impl DragonClan {
         DragonClan {
             name,
             dragons: vec![],
+            day: 0,
+            interactions_today: 0,
+            opinion_decay: OpinionDecay::default(),
+            trait_development: TraitDevelopmentConfig::default(),
+            state: ClanState::default(),
+            chronicle: Chronicle::default(),
+            name_method: NameMethod::default(),
+            name_style: DEFAULT_NAME_STYLE.to_string(),
+            title: None,
+            auto_rename: false,
+            communications: CommunicationTally::default(),
+            factions: Factions::default(),
+            recorder: Recorder::default(),
+            log: InteractionLog::default(),
         }
     }
 
@@ -33,6 +90,7 @@ impl DragonClan {
     #[wasm_bindgen(setter)]
     pub fn set_name(&mut self, name: String) {
         self.name = name;
+        self.title = None;
     }
 
     pub fn add_dragon(&mut self, dragon: Dragon) {
@@ -51,7 +109,14 @@ impl DragonClan {
 
     pub fn remove_dragon(&mut self, index: usize) -> bool {
         if index < self.dragons.len() {
-            self.dragons.remove(index);
+            let dragon = self.dragons.remove(index);
+            let name = dragon.name();
+            for other in self.dragons.iter_mut() {
+                other.release_oaths_to(&name);
+            }
+            self.factions.remove_member(self.day, &name);
+            self.recorder.forget(&name);
+            self.chronicle.record_departure(self.day, &name);
             true
         } else {
             false
@@ -60,6 +125,21 @@ impl DragonClan {
 
     pub fn clear(&mut self) {
         self.dragons.clear();
+        self.day = 0;
+        self.interactions_today = 0;
+        self.state = ClanState::default();
+        self.chronicle = Chronicle::default();
+        self.title = None;
+        self.communications = CommunicationTally::default();
+        self.factions = Factions::default();
+        self.recorder.clear();
+        self.log.clear();
+    }
+
+    /// Current day on the clan clock
+    #[wasm_bindgen(getter)]
+    pub fn day(&self) -> u32 {
+        self.day
     }
 
     pub fn simulate_interactions(&mut self, count: usize) -> Vec<InteractionResult> {
@@ -74,17 +154,9 @@ impl DragonClan {
 
         for _ in 0..count {
             let dragon1_idx = rng.gen_range(0..self.dragons.len());
-            let mut dragon2_idx = rng.gen_range(0..self.dragons.len());
-            while dragon2_idx == dragon1_idx {
-                dragon2_idx = rng.gen_range(0..self.dragons.len());
-            }
+            let dragon2_idx = self.choose_partner(dragon1_idx, &mut rng);
 
-            // We need to get mutable references, but Rust's borrow checker makes this tricky
-            // We'll use indices and unsafe, or restructure. For now, let's use a different approach.
-            // Actually, we need to restructure this to work with WASM bindings.
-            // Let's create a method that takes indices.
-            let result = self.simulate_interaction_between(dragon1_idx, dragon2_idx);
-            if let Some(interaction) = result {
+            if let Some((_, interaction)) = self.select_action(dragon1_idx, dragon2_idx) {
                 interactions.push(interaction);
             }
         }
@@ -92,11 +164,162 @@ impl DragonClan {
         interactions
     }
 
+    /// Pick another dragon for the dragon at idx to talk to
+    /// Members of its faction are FACTION_PARTNER_WEIGHT times as likely to be picked as anyone else
+    fn choose_partner(&self, idx: usize, rng: &mut impl rand::Rng) -> usize {
+        let faction = self.factions.faction_of(&self.dragons[idx].name());
+        let weights: Vec<u32> = self
+            .dragons
+            .iter()
+            .enumerate()
+            .map(|(other_idx, other)| {
+                if other_idx == idx {
+                    0
+                } else if faction.is_some_and(|faction| faction.contains(&other.name())) {
+                    FACTION_PARTNER_WEIGHT
+                } else {
+                    1
+                }
+            })
+            .collect();
+        let mut roll = rng.gen_range(0..weights.iter().sum::<u32>());
+        weights
+            .iter()
+            .position(|&weight| {
+                if roll < weight {
+                    true
+                } else {
+                    roll -= weight;
+                    false
+                }
+            })
+            .unwrap_or(0)
+    }
+
+    /// Decide whether the dragon at idx1 uses its elemental ability or communicates with the dragon at idx2
+    /// Returns the index of the dragon acted upon, None for abilities without a target
+    fn select_action(&mut self, idx1: usize, idx2: usize) -> Option<(Option<usize>, InteractionResult)> {
+        use rand::Rng;
+        let ready = self.dragons.get(idx1)?.ability_cooldown(self.day) == 0;
+        if ready && rand::thread_rng().gen_bool(ABILITY_CHANCE) {
+            if let Some(action) = self.use_ability(idx1, None) {
+                return Some(action);
+            }
+        }
+
+        self.simulate_interaction_between(idx1, idx2)
+            .map(|result| (Some(idx2), result))
+    }
+
+    /// Have the dragon at idx use its elemental ability, optionally on a chosen target
+    /// Without a target, the dragon picks one itself
+    /// Returns the target, None for abilities without one, and the result
+    /// Returns None if the dragon's element has no ability, or it is cooling down or has no suitable target
+    pub(crate) fn use_ability(&mut self, idx: usize, target: Option<usize>) -> Option<(Option<usize>, InteractionResult)> {
+        let actor = self.dragons.get(idx)?;
+        if actor.ability_cooldown(self.day) > 0 {
+            return None;
+        }
+
+        let kind = actor.ability()?;
+        let target = if !kind.targets_other() {
+            None
+        } else {
+            match target {
+                Some(target) if target < self.dragons.len() && target != idx => Some(target),
+                Some(_) => return None,
+                None => Some(ability::choose_target(&self.dragons, idx, kind)?),
+            }
+        };
+
+        let before = target.map(|target| self.opinions_between(idx, target));
+        let (description, opinion_change) =
+            ability::use_ability(&mut self.dragons, idx, target, kind, &mut self.state, self.day)?;
+        self.dragons[idx].start_ability_cooldown(self.day);
+        self.dragons[idx].record_ability_use(kind);
+        let mut result = InteractionResult::new(description, opinion_change).with_ability(kind);
+        if let (Some(target), Some(before), Some((comm_type, tone))) = (target, before, kind.exchange()) {
+            result = result.with_exchange(Communication::new(comm_type, String::new(), 0), tone, 0);
+            let new_bond = self.record_exchange(idx, target, &result);
+            result = result
+                .with_new_bond(new_bond)
+                .with_opinions(self.opinion_snapshot(idx, target, before));
+        }
+        self.record_in_chronicle(idx, target, &result);
+        self.record_in_log(idx, target, &result);
+        self.advance_clock();
+
+        Some((target, result))
+    }
+
+    /// How names are made up for dragons that hatch in this clan
+    pub(crate) fn set_name_method(&mut self, method: NameMethod) {
+        self.name_method = method;
+    }
+
+    /// Which name style pack hatchlings and the clan's own name are drawn from
+    pub(crate) fn set_name_style(&mut self, style: &str) {
+        self.name_style = style.to_string();
+    }
+
+    /// Which elements and values the clan's members have, as its name reflects them
+    pub(crate) fn composition(&self) -> ClanComposition {
+        composition_of(&self.dragons)
+    }
+
+    /// Name the clan after its dominant element and value
+    /// Returns false, leaving the name alone, if the clan has no dragons
+    pub(crate) fn name_from_composition(&mut self) -> bool {
+        let composition = self.composition();
+        let title = name_style::with_style(&self.name_style, |style| {
+            ClanTitle::from_composition(&composition, style, &mut rand::thread_rng())
+        });
+        match title {
+            Some(title) => {
+                self.name = title.name();
+                self.title = Some(title);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub(crate) fn set_auto_rename(&mut self, auto_rename: bool) {
+        self.auto_rename = auto_rename;
+    }
+
+    /// Rename or re-title a clan named after its composition if the composition has shifted enough
+    /// Clans given a name by hand keep it
+    fn reconsider_name(&mut self) {
+        let Some(title) = &self.title else {
+            return;
+        };
+        let composition = self.composition();
+        let new_title = name_style::with_style(&self.name_style, |style| {
+            title.reconsider(&composition, style, &mut rand::thread_rng())
+        });
+        if let Some(new_title) = new_title {
+            let new_name = new_title.name();
+            if new_name != self.name {
+                self.chronicle.record_rename(self.day, &self.name, &new_name);
+                self.name = new_name;
+            }
+            self.title = Some(new_title);
+        }
+    }
+
+    /// Clan-wide state built up by elemental abilities
+    pub(crate) fn state(&self) -> ClanState {
+        self.state
+    }
+
     fn simulate_interaction_between(&mut self, idx1: usize, idx2: usize) -> Option<InteractionResult> {
         if idx1 >= self.dragons.len() || idx2 >= self.dragons.len() || idx1 == idx2 {
             return None;
         }
 
+        let before = self.opinions_between(idx1, idx2);
+
         // The interaction system now handles both sides internally
         // Dragon1 generates a communication, and we simulate the response
         let dragon2_clone = self.dragons[idx2].clone();
@@ -105,19 +328,410 @@ impl DragonClan {
             dragon1.interact_with(&dragon2_clone)
         };
 
+        // The receiver takes it better from its own faction, and worse from a rival one
+        let faction_modifier = self.factions.opinion_modifier(&dragon2_clone.name(), &self.dragons[idx1].name());
+        let result = result.with_opinion_modifier(faction_modifier);
+
         // Update dragon2's opinion based on the interaction
         // The interact_with method already handles the receiver's response internally,
         // but we need to update dragon2's relationship as well
         {
             let dragon1_clone = self.dragons[idx1].clone();
-            // Use the opinion change from the result (which is the receiver's perspective)
+            // Use the changes from the result (which are the receiver's perspective)
             let dragon2 = &mut self.dragons[idx2];
-            dragon2.update_opinion_from_interaction(&dragon1_clone, result.opinion_change());
+            dragon2.receive_interaction(&dragon1_clone, &result);
         }
 
+        let sender_name = self.dragons[idx1].name();
+        let receiver_name = self.dragons[idx2].name();
+        let day = self.day;
+        self.dragons[idx1].relationship_with_mut(&receiver_name).record_contact(day);
+        self.dragons[idx2].relationship_with_mut(&sender_name).record_contact(day);
+
+        // The listener's values drift toward (or away from) what the speaker expressed
+        self.dragons[idx2].absorb_influence(&sender_name, &result, day);
+
+        // The speaker's traits develop from how they were received
+        let development = self.trait_development;
+        let receiver_dominance = self.dragons[idx2].dominance();
+        self.dragons[idx1].develop_from_interaction(receiver_dominance, &result, &development);
+
+        let new_bond = self.record_exchange(idx1, idx2, &result);
+        let opinions = self.opinion_snapshot(idx1, idx2, before);
+        let broke_promise = self.dragons[idx1].record_deeds(&receiver_name, &result, day);
+        let result = result
+            .with_new_bond(new_bond)
+            .with_opinions(opinions)
+            .with_broken_promise(broke_promise);
+        self.record_in_chronicle(idx1, Some(idx2), &result);
+        self.record_in_log(idx1, Some(idx2), &result);
+
+        self.advance_clock();
+
         Some(result)
     }
 
+    fn record_in_chronicle(&mut self, idx1: usize, idx2: Option<usize>, result: &InteractionResult) {
+        let sender = self.dragons[idx1].name();
+        let receiver = idx2.map(|idx2| self.dragons[idx2].name());
+        self.chronicle.record_interaction(self.day, &sender, receiver.as_deref(), result);
+    }
+
+    /// Add an interaction to the log, stamped with the current time on the clan clock
+    fn record_in_log(&mut self, idx1: usize, idx2: Option<usize>, result: &InteractionResult) {
+        let communication = result.ability().is_none().then(|| result.communication());
+        let (sender_opinion_change, receiver_opinion_change) = result.opinions().map_or((0, 0), |opinions| {
+            (
+                opinions.sender_after - opinions.sender_before,
+                opinions.receiver_after - opinions.receiver_before,
+            )
+        });
+        self.log.record(LogEntry {
+            sequence: 0,
+            time: self.time(),
+            day: self.day,
+            sender: self.dragons[idx1].name(),
+            receiver: idx2.map(|idx2| self.dragons[idx2].name()),
+            comm_type: communication.map(|c| c.comm_type.as_str().to_string()),
+            ability: result.ability().map(|kind| kind.as_str().to_string()),
+            primary_value: communication.and_then(|c| c.primary_value.clone()),
+            primary_trait: communication.and_then(|c| c.primary_trait.clone()),
+            tone: communication.map(|_| result.response_tone().as_str().to_string()),
+            intensity: communication.map(|c| c.intensity),
+            sender_opinion_change,
+            receiver_opinion_change,
+        });
+    }
+
+    /// Time on the clan clock in days, e.g. 3.5 for halfway through day 3
+    fn time(&self) -> f64 {
+        self.day as f64 + self.interactions_today as f64 / INTERACTIONS_PER_DAY as f64
+    }
+
+    /// The dragon the rest of the clan respects most, if anyone has earned respect yet
+    /// Ties go to the more dominant dragon
+    pub(crate) fn leader_index(&self) -> Option<usize> {
+        (0..self.dragons.len())
+            .map(|candidate| {
+                let respect: i32 = self
+                    .dragons
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i != candidate)
+                    .filter_map(|(_, dragon)| dragon.relationship_with(&self.dragons[candidate]))
+                    .map(|relationship| relationship.respect())
+                    .sum();
+                (candidate, respect)
+            })
+            .filter(|(_, respect)| *respect > 0)
+            .max_by_key(|(candidate, respect)| (*respect, self.dragons[*candidate].dominance()))
+            .map(|(candidate, _)| candidate)
+    }
+
+    /// The clan's history of noteworthy developments
+    pub(crate) fn chronicle(&self) -> &Chronicle {
+        &self.chronicle
+    }
+
+    /// Opinions of the dragons at idx1 and idx2 of each other
+    fn opinions_between(&self, idx1: usize, idx2: usize) -> (i32, i32) {
+        (
+            self.get_opinion_by_indices(idx1, idx2).unwrap_or(0),
+            self.get_opinion_by_indices(idx2, idx1).unwrap_or(0),
+        )
+    }
+
+    /// Compare the opinions of the dragons at idx1 and idx2 now with what they were before
+    fn opinion_snapshot(&self, idx1: usize, idx2: usize, before: (i32, i32)) -> OpinionSnapshot {
+        let after = self.opinions_between(idx1, idx2);
+        OpinionSnapshot {
+            sender_before: before.0,
+            sender_after: after.0,
+            receiver_before: before.1,
+            receiver_after: after.1,
+        }
+    }
+
+    /// Bookkeeping shared by communications and abilities aimed at another dragon: both dragons remember
+    /// the exchange from their own perspective, the clan's mood takes note and the pair may form a bond
+    /// Returns the new bond from the perspective of the dragon at idx1
+    fn record_exchange(&mut self, idx1: usize, idx2: usize, result: &InteractionResult) -> Option<BondType> {
+        let dragon1_name = self.dragons[idx1].name();
+        let dragon2_name = self.dragons[idx2].name();
+        self.dragons[idx1].remember(Memory {
+            other_name: dragon2_name,
+            initiated: true,
+            comm_type: result.comm_type(),
+            tone: result.response_tone(),
+            opinion_change: result.sender_opinion_change(),
+            day: self.day,
+        });
+        self.dragons[idx2].remember(Memory {
+            other_name: dragon1_name,
+            initiated: false,
+            comm_type: result.comm_type(),
+            tone: result.response_tone(),
+            opinion_change: result.opinion_change(),
+            day: self.day,
+        });
+
+        self.communications.record(result.comm_type(), result.response_tone());
+        self.update_bonds(idx1, idx2, result)
+    }
+
+    /// Count the exchange towards courtship, mentorship and rivalry, and form any bond the pair has earned
+    /// Returns the new bond from the perspective of the dragon at idx1
+    fn update_bonds(&mut self, idx1: usize, idx2: usize, result: &InteractionResult) -> Option<BondType> {
+        let sender_age = self.dragons[idx1].age();
+        let receiver_age = self.dragons[idx2].age();
+
+        // Both sides track the same progress so either can be checked later
+        for (holder, other) in [(idx1, idx2), (idx2, idx1)] {
+            let other_name = self.dragons[other].name();
+            self.dragons[holder]
+                .relationship_with_mut(&other_name)
+                .bond_progress_mut()
+                .record_exchange(result.comm_type(), result.response_tone(), sender_age, receiver_age);
+        }
+
+        let new_bond = {
+            let sender = &self.dragons[idx1];
+            let receiver = &self.dragons[idx2];
+            let sender_relationship = sender.relationship_with(receiver)?;
+            let receiver_relationship = receiver.relationship_with(sender)?;
+            let sender_bonds = sender.bond_types_with(receiver);
+            let receiver_bonds = receiver.bond_types_with(sender);
+            sender_relationship.bond_progress().check_new_bond(
+                &BondCandidate {
+                    age: sender_age,
+                    opinion: sender_relationship.opinion(),
+                    respect: sender_relationship.respect(),
+                    has_mate: sender.has_mate(),
+                    is_parent: sender.is_parent_of(receiver),
+                    bonds: &sender_bonds,
+                },
+                &BondCandidate {
+                    age: receiver_age,
+                    opinion: receiver_relationship.opinion(),
+                    respect: receiver_relationship.respect(),
+                    has_mate: receiver.has_mate(),
+                    is_parent: receiver.is_parent_of(sender),
+                    bonds: &receiver_bonds,
+                },
+            )?
+        };
+
+        let sender_name = self.dragons[idx1].name();
+        let receiver_name = self.dragons[idx2].name();
+        self.dragons[idx1].form_bond(&receiver_name, new_bond, self.day);
+        self.dragons[idx2].form_bond(&sender_name, new_bond.reciprocal(), self.day);
+        Some(new_bond)
+    }
+
+    /// Hatch an egg from two mated dragons
+    /// The hatchling takes one parent's element, becomes a hybrid when its parents' elements differ,
+    /// and becomes a sibling of every dragon sharing a parent
+    /// Returns the index of the hatchling, or None if the dragons are not mates
+    pub(crate) fn hatch(&mut self, idx1: usize, idx2: usize) -> Option<usize> {
+        if idx1 >= self.dragons.len() || idx2 >= self.dragons.len() || idx1 == idx2 {
+            return None;
+        }
+
+        let parent1 = &self.dragons[idx1];
+        let parent2 = &self.dragons[idx2];
+        if !parent1.bond_types_with(parent2).contains(&BondType::Mate) {
+            return None;
+        }
+
+        // The hatchling takes one parent's primary element; any other element in the
+        // parents' lineage can become its secondary element
+        use rand::seq::SliceRandom;
+        let mut rng = rand::thread_rng();
+        let element = [parent1.element(), parent2.element()].choose(&mut rng)?.clone();
+        let mut lineage: Vec<String> = [parent1, parent2]
+            .iter()
+            .flat_map(|parent| std::iter::once(parent.element()).chain(parent.secondary_element()))
+            .filter(|other| *other != element)
+            .collect();
+        lineage.sort();
+        lineage.dedup();
+        let secondary_element = lineage.choose(&mut rng).cloned();
+        let parent1_name = parent1.name();
+        let parent2_name = parent2.name();
+
+        let name = match secondary_element {
+            Some(ref secondary) => name_generator::generate_hybrid_name(self.name_method, &self.name_style, &element, secondary),
+            None => name_generator::generate_name(self.name_method, &self.name_style, Some(&element)),
+        };
+        let mut hatchling = Dragon::new_hybrid(name.clone(), element, secondary_element, 0);
+        hatchling.set_parents(parent1_name.clone(), parent2_name.clone());
+
+        for dragon in self.dragons.iter_mut() {
+            let shares_parent = dragon.parents().is_some_and(|(p1, p2)| {
+                [p1, p2].iter().any(|p| **p == parent1_name || **p == parent2_name)
+            });
+            if shares_parent {
+                dragon.form_bond(&name, BondType::Sibling, self.day);
+                hatchling.form_bond(&dragon.name(), BondType::Sibling, self.day);
+            }
+        }
+
+        self.dragons.push(hatchling);
+        self.chronicle.record_hatching(self.day, &name, &parent1_name, &parent2_name);
+        Some(self.dragons.len() - 1)
+    }
+
+    /// Get what the dragon at idx1 believes about the dragon at idx2
+    /// This is NOT exposed to WASM - it's an internal method
+    pub(crate) fn get_beliefs_by_indices(&self, idx1: usize, idx2: usize) -> Option<&Beliefs> {
+        if idx1 >= self.dragons.len() || idx2 >= self.dragons.len() || idx1 == idx2 {
+            return None;
+        }
+
+        self.dragons[idx1].beliefs_about(&self.dragons[idx2])
+    }
+
+    /// Get the recorded value shifts of the dragon at idx
+    /// This is NOT exposed to WASM - it's an internal method
+    pub(crate) fn get_value_history_by_index(&self, idx: usize) -> Option<impl Iterator<Item = &ValueShift>> {
+        self.dragons.get(idx).map(|dragon| dragon.value_history())
+    }
+
+    /// Get the bonds held by the dragon at idx
+    /// This is NOT exposed to WASM - it's an internal method
+    pub(crate) fn get_bonds_by_index(&self, idx: usize) -> Option<&[Bond]> {
+        self.dragons.get(idx).map(|dragon| dragon.bonds())
+    }
+
+    /// Advance the clan clock by one interaction, rolling over to the next day when needed
+    fn advance_clock(&mut self) {
+        self.interactions_today += 1;
+        if self.interactions_today >= INTERACTIONS_PER_DAY {
+            self.start_new_day();
+        }
+        self.tick_recorder();
+    }
+
+    /// Let the recorder know the clock has ticked, sampling opinions and clan metrics if it is due
+    fn tick_recorder(&mut self) {
+        if !self.recorder.tick() {
+            return;
+        }
+        let time = self.time();
+        let names = self.dragon_names();
+        let (opinions, _) = self.relationship_matrices();
+        let cohesion = self.cohesion();
+        let metrics = ClanMetrics {
+            dragon_count: self.dragons.len() as u32,
+            average_opinion: cohesion.average_opinion,
+            polarization: cohesion.polarization,
+            reciprocity: cohesion.reciprocity,
+            friendships: cohesion.friendships,
+            rivalries: cohesion.rivalries,
+            mood: self.communications.mood(),
+            morale: self.state.morale,
+        };
+        self.recorder.record(time, &names, &opinions, metrics);
+    }
+
+    /// Move the clock to the next day and apply everything that happens overnight
+    fn start_new_day(&mut self) {
+        self.interactions_today = 0;
+        self.day += 1;
+
+        let today = self.day;
+        let decay = self.opinion_decay;
+        let development = self.trait_development;
+        let new_year = today.is_multiple_of(DAYS_PER_YEAR);
+        for dragon in self.dragons.iter_mut() {
+            dragon.decay_opinions(today, &decay);
+            dragon.settle_oaths(today);
+            if new_year {
+                dragon.grow_older(&development);
+            }
+        }
+
+        // Overnight, the clan settles on who it looks to for leadership
+        let leader = self.leader_index().map(|idx| self.dragons[idx].name());
+        self.chronicle.record_leader(today, leader);
+
+        if self.auto_rename {
+            self.reconsider_name();
+        }
+        self.update_factions();
+    }
+
+    /// Detect the factions the clan has divided into today and match them to yesterday's,
+    /// naming any new faction after its members' dominant element and value
+    fn update_factions(&mut self) {
+        let (opinions, _) = self.relationship_matrices();
+        let names = self.dragon_names();
+        let groups = faction::detect(names.len(), &opinions)
+            .iter()
+            .map(|group| group.iter().map(|&i| names[i].clone()).collect())
+            .collect();
+
+        let dragons = &self.dragons;
+        let style = &self.name_style;
+        self.factions.update(self.day, groups, |members| {
+            let composition = composition_of(dragons.iter().filter(|dragon| members.contains(&dragon.name())));
+            name_style::with_style(style, |style| {
+                ClanTitle::from_composition(&composition, style, &mut rand::thread_rng())
+            })
+            .map(|title| title.name())
+            .unwrap_or_default()
+        });
+    }
+
+    /// The clan's most recent interactions
+    pub(crate) fn log(&self) -> &InteractionLog {
+        &self.log
+    }
+
+    pub(crate) fn set_log_capacity(&mut self, capacity: usize) {
+        self.log.set_capacity(capacity);
+    }
+
+    pub(crate) fn configure_recorder(&mut self, config: RecorderConfig) {
+        self.recorder.configure(config);
+    }
+
+    /// Opinion and clan metric series recorded so far
+    pub(crate) fn recorder(&self) -> &Recorder {
+        &self.recorder
+    }
+
+    /// Current factions, oldest first
+    pub(crate) fn factions(&self) -> &[Faction] {
+        self.factions.all()
+    }
+
+    pub(crate) fn faction(&self, id: u32) -> Option<&Faction> {
+        self.factions.get(id)
+    }
+
+    /// Faction changes since they were last taken
+    pub(crate) fn take_faction_events(&mut self) -> Vec<FactionEvent> {
+        self.factions.take_events()
+    }
+
+    /// Let days pass without any interactions
+    /// Each day counts as one tick for the recorder
+    pub fn advance_days(&mut self, days: u32) {
+        for _ in 0..days {
+            self.start_new_day();
+            self.tick_recorder();
+        }
+    }
+
+    pub(crate) fn set_opinion_decay(&mut self, decay: OpinionDecay) {
+        self.opinion_decay = decay;
+    }
+
+    pub(crate) fn set_trait_development(&mut self, config: TraitDevelopmentConfig) {
+        self.trait_development = config;
+    }
+
     /// Simulate interactions and return results with indices
     /// This is used by the service layer to track which dragons interacted
     /// This is NOT exposed to WASM - it's an internal method
@@ -133,12 +747,9 @@ impl DragonClan {
 
         for _ in 0..count {
             let dragon1_idx = rng.gen_range(0..self.dragons.len());
-            let mut dragon2_idx = rng.gen_range(0..self.dragons.len());
-            while dragon2_idx == dragon1_idx {
-                dragon2_idx = rng.gen_range(0..self.dragons.len());
-            }
+            let dragon2_idx = self.choose_partner(dragon1_idx, &mut rng);
 
-            if let Some(result) = self.simulate_interaction_between(dragon1_idx, dragon2_idx) {
+            if let Some((dragon2_idx, result)) = self.select_action(dragon1_idx, dragon2_idx) {
                 interactions.push(InteractionWithIndices {
                     dragon1_idx,
                     dragon2_idx,
@@ -158,9 +769,106 @@ impl DragonClan {
             return None;
         }
 
-        let dragon2 = self.dragons[idx2].clone();
-        let dragon1 = &self.dragons[idx1];
-        Some(dragon1.get_opinion_of(&dragon2))
+        Some(self.dragons[idx1].get_opinion_of(&self.dragons[idx2]))
+    }
+
+    /// Every dragon's opinion of every other, and how often they have interacted, in one pass
+    /// Both are row-major n×n matrices where entry i * n + j is about dragon i's view of dragon j;
+    /// the diagonal and pairs that have never met are 0
+    pub(crate) fn relationship_matrices(&self) -> (Vec<i32>, Vec<u32>) {
+        let n = self.dragons.len();
+        let mut opinions = vec![0; n * n];
+        let mut interaction_counts = vec![0; n * n];
+        for (i, dragon) in self.dragons.iter().enumerate() {
+            for (j, other) in self.dragons.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                if let Some(relationship) = dragon.relationship_with(other) {
+                    opinions[i * n + j] = relationship.opinion();
+                    interaction_counts[i * n + j] = relationship.interaction_count();
+                }
+            }
+        }
+        (opinions, interaction_counts)
+    }
+
+    /// Get the memories of the dragon at idx
+    /// This is NOT exposed to WASM - it's an internal method
+    pub(crate) fn get_memories_by_index(&self, idx: usize) -> Option<&[Memory]> {
+        self.dragons.get(idx).map(|dragon| dragon.memories())
+    }
+
+    /// Get the memories the dragon at idx1 holds about the dragon at idx2
+    /// This is NOT exposed to WASM - it's an internal method
+    pub(crate) fn get_memories_about_by_indices(&self, idx1: usize, idx2: usize) -> Option<Vec<&Memory>> {
+        if idx1 >= self.dragons.len() || idx2 >= self.dragons.len() || idx1 == idx2 {
+            return None;
+        }
+
+        Some(self.dragons[idx1].memories_of(&self.dragons[idx2]))
+    }
+
+    /// Get the relationship dragon at idx1 has with dragon at idx2
+    /// This is NOT exposed to WASM - it's an internal method
+    /// Returns None if the indices are invalid or the dragons have not interacted yet
+    pub(crate) fn get_relationship_by_indices(&self, idx1: usize, idx2: usize) -> Option<&Relationship> {
+        if idx1 >= self.dragons.len() || idx2 >= self.dragons.len() || idx1 == idx2 {
+            return None;
+        }
+
+        self.dragons[idx1].relationship_with(&self.dragons[idx2])
+    }
+
+    /// The clan's positive opinions as a weighted graph, for social analytics
+    pub(crate) fn social_graph(&self) -> SocialGraph {
+        let (opinions, _) = self.relationship_matrices();
+        SocialGraph::from_opinions(self.dragons.len(), &opinions)
+    }
+
+    /// How well the dragons get on, over every relationship that exists
+    pub(crate) fn cohesion(&self) -> Cohesion {
+        let mut opinions = HashMap::new();
+        for (i, dragon) in self.dragons.iter().enumerate() {
+            for (j, other) in self.dragons.iter().enumerate() {
+                if let Some(relationship) = dragon.relationship_with(other).filter(|_| i != j) {
+                    opinions.insert((i, j), relationship.opinion());
+                }
+            }
+        }
+        Cohesion::measure(&opinions)
+    }
+
+    /// Relationships by their description label, e.g. ("trusted_friend", 3), sorted by label
+    pub(crate) fn relationship_labels(&self) -> Vec<(String, u32)> {
+        stats::tally(self.dragons.iter().flat_map(|dragon| {
+            self.dragons
+                .iter()
+                .filter(move |other| other.name() != dragon.name())
+                .filter_map(move |other| dragon.relationship_with(other))
+                .map(|relationship| relationship.description_label())
+        }))
+    }
+
+    /// Dragons by primary element, sorted by element
+    pub(crate) fn element_distribution(&self) -> Vec<(String, u32)> {
+        let elements: Vec<String> = self.dragons.iter().map(|dragon| dragon.element()).collect();
+        stats::tally(elements.iter().map(String::as_str))
+    }
+
+    /// Dragons by interaction style label, sorted by style
+    pub(crate) fn interaction_style_distribution(&self) -> Vec<(String, u32)> {
+        stats::tally(self.dragons.iter().map(|dragon| dragon.interaction_style()))
+    }
+
+    /// Communications so far by type and by how they were received
+    pub(crate) fn communications(&self) -> &CommunicationTally {
+        &self.communications
+    }
+
+    /// Names of the dragons, in index order
+    pub(crate) fn dragon_names(&self) -> Vec<String> {
+        self.dragons.iter().map(|dragon| dragon.name()).collect()
     }
 
     /// Get relationship info between two dragons by indices
@@ -171,10 +879,94 @@ impl DragonClan {
             return None;
         }
 
-        let dragon2 = self.dragons[idx2].clone();
-        let dragon1 = &self.dragons[idx1];
-        Some(dragon1.get_relationship_info(&dragon2))
+        Some(self.dragons[idx1].get_relationship_info(&self.dragons[idx2]))
+    }
+}
+
+/// Which elements and values a group of dragons has, as a clan or faction name reflects them
+fn composition_of<'a>(dragons: impl IntoIterator<Item = &'a Dragon>) -> ClanComposition {
+    let dragons: Vec<&Dragon> = dragons.into_iter().collect();
+    let mut element_weights: Vec<(String, f64)> = Vec::new();
+    let mut add_weight = |element: String, weight: f64| {
+        match element_weights.iter_mut().find(|(name, _)| *name == element) {
+            Some(entry) => entry.1 += weight,
+            None => element_weights.push((element, weight)),
+        }
+    };
+    for dragon in &dragons {
+        add_weight(dragon.element(), 1.0);
+        if let Some(secondary) = dragon.secondary_element() {
+            add_weight(secondary, SECONDARY_ELEMENT_WEIGHT);
+        }
+    }
+
+    let value_averages = if dragons.is_empty() {
+        Vec::new()
+    } else {
+        VALUE_NAMES
+            .iter()
+            .map(|value| {
+                let total: u32 = dragons.iter().map(|dragon| dragon.value(value)).sum();
+                (value.to_string(), total as f64 / dragons.len() as f64)
+            })
+            .collect()
+    };
+
+    ClanComposition {
+        element_weights,
+        value_averages,
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
 
+    #[test]
+    fn test_relationship_matrices_match_pairwise_lookups() {
+        let mut clan = DragonClan::new("The Test Clan".to_string());
+        for (name, element) in [("Ignis", "Fire"), ("Aqua", "Water"), ("Terra", "Earth")] {
+            clan.add_dragon(Dragon::new(name.to_string(), element.to_string(), 5));
+        }
+        clan.simulate_interactions(30);
+
+        let (opinions, interaction_counts) = clan.relationship_matrices();
+        assert_eq!(opinions.len(), 9);
+        for i in 0..3 {
+            assert_eq!((opinions[i * 3 + i], interaction_counts[i * 3 + i]), (0, 0));
+            for j in (0..3).filter(|&j| j != i) {
+                assert_eq!(Some(opinions[i * 3 + j]), clan.get_opinion_by_indices(i, j));
+                let count = clan.get_relationship_by_indices(i, j).map_or(0, |r| r.interaction_count());
+                assert_eq!(interaction_counts[i * 3 + j], count);
+            }
+        }
+        assert!(interaction_counts.iter().sum::<u32>() > 0);
+    }
+
+    #[test]
+    fn test_hatchling_is_sibling_to_its_parents_other_young() {
+        let mut clan = DragonClan::new("The Test Clan".to_string());
+        for (name, element) in [("Ignis", "Fire"), ("Aqua", "Water"), ("Terra", "Earth")] {
+            clan.add_dragon(Dragon::new(name.to_string(), element.to_string(), 8));
+        }
+        assert_eq!(clan.hatch(0, 1), None);
+
+        let names = ["Ignis", "Aqua"];
+        for (dragon, other) in [(0, 1), (1, 0)] {
+            clan.dragons[dragon].form_bond(names[other], BondType::Mate, 0);
+        }
+        let first = clan.hatch(0, 1).unwrap();
+        let second = clan.hatch(1, 0).unwrap();
+        let first_name = clan.dragons[first].name();
+        let second_name = clan.dragons[second].name();
+        let is_sibling = |holder: usize, other: &str| {
+            clan.dragons[holder]
+                .bonds()
+                .iter()
+                .any(|bond| bond.other_name == other && bond.bond_type == BondType::Sibling)
+        };
+        assert!(is_sibling(first, &second_name));
+        assert!(is_sibling(second, &first_name));
+        assert!(clan.dragons[2].bonds().is_empty());
+    }
+}
@@ -0,0 +1,172 @@
+use crate::name_style::NameStyle;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// How much more weight a new element needs than the clan's namesake element before the clan
+/// takes a new name; one dragon counts 1, and a secondary element counts SECONDARY_ELEMENT_WEIGHT
+pub const RENAME_ELEMENT_MARGIN: f64 = 1.0;
+
+/// How many points higher the clan's average for a new value must be than for its namesake
+/// value before the clan takes a new title
+pub const RETITLE_VALUE_MARGIN: f64 = 5.0;
+
+/// The make-up of a clan that its name reflects
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClanComposition {
+    /// Weight of each element among the members, in order of first appearance
+    pub element_weights: Vec<(String, f64)>,
+    /// Average score of each value across the members
+    pub value_averages: Vec<(String, f64)>,
+}
+
+impl ClanComposition {
+    /// The element with the most weight; the first listed wins ties
+    pub fn dominant_element(&self) -> Option<&str> {
+        dominant(&self.element_weights)
+    }
+
+    /// The value with the highest average; the first listed wins ties
+    pub fn dominant_value(&self) -> Option<&str> {
+        dominant(&self.value_averages)
+    }
+
+    fn element_weight(&self, element: &str) -> f64 {
+        weight_of(&self.element_weights, element)
+    }
+
+    fn value_average(&self, value: &str) -> f64 {
+        weight_of(&self.value_averages, value)
+    }
+}
+
+fn dominant(entries: &[(String, f64)]) -> Option<&str> {
+    let mut best: Option<&(String, f64)> = None;
+    for entry in entries {
+        if best.is_none_or(|(_, weight)| entry.1 > *weight) {
+            best = Some(entry);
+        }
+    }
+    best.map(|(name, _)| name.as_str())
+}
+
+fn weight_of(entries: &[(String, f64)], name: &str) -> f64 {
+    entries.iter().find(|(n, _)| n == name).map(|(_, weight)| *weight).unwrap_or(0.0)
+}
+
+/// A clan name and the element and value it was drawn from
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClanTitle {
+    pub element: String,
+    pub value: String,
+    pub adjective: String,
+    pub noun: String,
+}
+
+impl ClanTitle {
+    /// Name a clan after its dominant element and value in a name style, e.g. "The Frozen Council"
+    /// for an ice-heavy clan that prizes wisdom
+    /// Returns None for a clan with no members
+    pub fn from_composition(composition: &ClanComposition, style: &NameStyle, rng: &mut impl Rng) -> Option<Self> {
+        let element = composition.dominant_element()?;
+        let value = composition.dominant_value()?;
+        Some(ClanTitle {
+            element: element.to_string(),
+            value: value.to_string(),
+            adjective: adjective_for(style, element, rng),
+            noun: noun_for(style, value, rng),
+        })
+    }
+
+    pub fn name(&self) -> String {
+        format!("The {} {}", self.adjective, self.noun)
+    }
+
+    /// The title the clan should take now its composition has shifted, if it has shifted enough
+    /// A new dominant element brings a whole new name; a new dominant value only a new noun
+    pub fn reconsider(&self, composition: &ClanComposition, style: &NameStyle, rng: &mut impl Rng) -> Option<Self> {
+        let element = composition.dominant_element()?;
+        if element != self.element
+            && composition.element_weight(element) - composition.element_weight(&self.element) >= RENAME_ELEMENT_MARGIN
+        {
+            return ClanTitle::from_composition(composition, style, rng);
+        }
+
+        let value = composition.dominant_value()?;
+        if value != self.value
+            && composition.value_average(value) - composition.value_average(&self.value) >= RETITLE_VALUE_MARGIN
+        {
+            return Some(ClanTitle {
+                value: value.to_string(),
+                noun: noun_for(style, value, rng),
+                ..self.clone()
+            });
+        }
+        None
+    }
+}
+
+/// Adjective for a clan of the element, or one of the style's general adjectives if it has none
+fn adjective_for(style: &NameStyle, element: &str, rng: &mut impl Rng) -> String {
+    let adjectives = style.clan_adjectives_for(element);
+    let adjectives = if adjectives.is_empty() { &style.clan_adjectives } else { &adjectives };
+    adjectives.choose(rng).cloned().unwrap_or_default()
+}
+
+/// Noun for a clan that prizes the value, or one of the style's general nouns if it has none
+fn noun_for(style: &NameStyle, value: &str, rng: &mut impl Rng) -> String {
+    let nouns = style.clan_nouns_for(value);
+    let nouns = if nouns.is_empty() { &style.clan_nouns } else { nouns };
+    nouns.choose(rng).cloned().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::element;
+    use crate::name_style::NameStyleRegistry;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn composition(elements: &[(&str, f64)], values: &[(&str, f64)]) -> ClanComposition {
+        let entries = |list: &[(&str, f64)]| list.iter().map(|(name, w)| (name.to_string(), *w)).collect();
+        ClanComposition {
+            element_weights: entries(elements),
+            value_averages: entries(values),
+        }
+    }
+
+    #[test]
+    fn test_name_follows_composition() {
+        let styles = NameStyleRegistry::default();
+        let classic = styles.get("classic").unwrap();
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let frozen = composition(&[("Ice", 3.0), ("Fire", 1.5)], &[("honor", 60.0), ("wisdom", 72.0)]);
+        let title = ClanTitle::from_composition(&frozen, classic, &mut rng).unwrap();
+        assert!(element::clan_adjectives("Ice").contains(&title.adjective));
+        assert!(["Council", "Circle", "Conclave"].contains(&title.noun.as_str()));
+        assert!(ClanTitle::from_composition(&ClanComposition::default(), classic, &mut rng).is_none());
+
+        // Small shifts leave the name alone
+        let shifted = composition(&[("Ice", 3.0), ("Fire", 3.5)], &[("honor", 75.0), ("wisdom", 72.0)]);
+        assert!(title.reconsider(&shifted, classic, &mut rng).is_none());
+
+        // A new favourite value re-titles the clan but keeps its adjective
+        let honorable = composition(&[("Ice", 3.0), ("Fire", 3.5)], &[("honor", 80.0), ("wisdom", 72.0)]);
+        let retitled = title.reconsider(&honorable, classic, &mut rng).unwrap();
+        assert_eq!((retitled.element.as_str(), retitled.adjective.as_str()), ("Ice", title.adjective.as_str()));
+        assert_eq!(retitled.value, "honor");
+
+        // A new dominant element renames it
+        let fiery = composition(&[("Ice", 3.0), ("Fire", 4.0)], &[("honor", 80.0)]);
+        let renamed = retitled.reconsider(&fiery, classic, &mut rng).unwrap();
+        assert_eq!(renamed.element, "Fire");
+        assert!(element::clan_adjectives("Fire").contains(&renamed.adjective));
+
+        // Other styles bring their own words
+        let norse = styles.get("norse").unwrap();
+        let title = ClanTitle::from_composition(&composition(&[("Ice", 1.0)], &[("wisdom", 50.0)]), norse, &mut rng).unwrap();
+        assert!(["Rime", "Frost"].contains(&title.adjective.as_str()));
+        assert!(["Runecasters", "Moot"].contains(&title.noun.as_str()));
+    }
+}
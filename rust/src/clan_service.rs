@@ -1,8 +1,23 @@
 use wasm_bindgen::prelude::*;
-use crate::dragon::{Dragon, DragonElement};
-use crate::clan::DragonClan;
-use crate::name_generator::generate_clan_name;
+use crate::dragon::{Dragon, OpinionSnapshot};
+use crate::affinity;
+use crate::element;
+use crate::faction::{Faction, FactionEventKind};
+use crate::interaction_log::{InteractionLog, LogFormat, DEFAULT_LOG_CAPACITY};
+use crate::locale;
+use crate::belief::{Belief, Beliefs};
+use crate::bond::Bond;
+use crate::character::TraitDevelopmentConfig;
+use crate::chronicle::{ChroniclePeriod, ChronicleSummary};
+use crate::clan::{DragonClan, InteractionWithIndices};
+use crate::memory::Memory;
+use crate::relationship::{OpinionDecay, Relationship};
+use crate::stats::Cohesion;
+use crate::values::ValueShift;
+use crate::name_generator::{self, NameMethod};
+use crate::name_style::{self, DEFAULT_NAME_STYLE};
 use crate::notification;
+use crate::recorder::RecorderConfig;
 use rand::Rng;
 
 /// Read-only dragon information exposed to TypeScript
@@ -11,8 +26,11 @@ use rand::Rng;
 pub struct DragonInfo {
     name: String,
     element: String,
+    secondary_element: Option<String>,
     age: u32,
     interaction_style: String,
+    epithet: Option<String>,
+    display_name: String,
 }
 
 #[wasm_bindgen]
@@ -27,6 +45,12 @@ impl DragonInfo {
         self.element.clone()
     }
 
+    /// Second element of a hybrid dragon, if any
+    #[wasm_bindgen(getter)]
+    pub fn secondary_element(&self) -> Option<String> {
+        self.secondary_element.clone()
+    }
+
     #[wasm_bindgen(getter)]
     pub fn age(&self) -> u32 {
         self.age
@@ -36,6 +60,18 @@ impl DragonInfo {
     pub fn interaction_style(&self) -> String {
         self.interaction_style.clone()
     }
+
+    /// Title earned from the dragon's deeds, e.g. "the Oathkeeper", if any
+    #[wasm_bindgen(getter)]
+    pub fn epithet(&self) -> Option<String> {
+        self.epithet.clone()
+    }
+
+    /// Name with any earned epithet, e.g. "Ignis the Oathkeeper"
+    #[wasm_bindgen(getter)]
+    pub fn display_name(&self) -> String {
+        self.display_name.clone()
+    }
 }
 
 impl DragonInfo {
@@ -43,8 +79,11 @@ impl DragonInfo {
         DragonInfo {
             name: dragon.name(),
             element: dragon.element(),
+            secondary_element: dragon.secondary_element(),
             age: dragon.age(),
             interaction_style: dragon.get_interaction_style(),
+            epithet: dragon.epithet().map(|epithet| epithet.title()),
+            display_name: dragon.display_name(),
         }
     }
 }
@@ -54,8 +93,25 @@ impl DragonInfo {
 pub struct InteractionEvent {
     description: String,
     dragon1_index: usize,
-    dragon2_index: usize,
+    dragon2_index: Option<usize>,
     opinion_change: i32,
+    ability: Option<String>,
+    exchange: Option<ExchangeDetails>,
+    opinions: Option<OpinionSnapshot>,
+}
+
+/// What was said in a communication and how it was received
+#[derive(Clone)]
+struct ExchangeDetails {
+    comm_type: String,
+    primary_value: Option<String>,
+    primary_trait: Option<String>,
+    intensity: u32,
+    content: String,
+    response_tone: String,
+    interpretation: String,
+    response: String,
+    sender_opinion_change: i32,
 }
 
 #[wasm_bindgen]
@@ -70,8 +126,9 @@ impl InteractionEvent {
         self.dragon1_index
     }
 
+    /// None for abilities that don't target another dragon
     #[wasm_bindgen(getter)]
-    pub fn dragon2_index(&self) -> usize {
+    pub fn dragon2_index(&self) -> Option<usize> {
         self.dragon2_index
     }
 
@@ -79,6 +136,814 @@ impl InteractionEvent {
     pub fn opinion_change(&self) -> i32 {
         self.opinion_change
     }
+
+    /// Elemental ability used instead of communicating, if any
+    #[wasm_bindgen(getter)]
+    pub fn ability(&self) -> Option<String> {
+        self.ability.clone()
+    }
+
+    /// One of "value alignment", "neutral", "confrontational", "friendly", "playful", "curious" or "shy"
+    /// None when an ability was used instead of communicating
+    #[wasm_bindgen(getter)]
+    pub fn comm_type(&self) -> Option<String> {
+        self.exchange.as_ref().map(|e| e.comm_type.clone())
+    }
+
+    /// Value the communication expressed, e.g. "honor"
+    #[wasm_bindgen(getter)]
+    pub fn primary_value(&self) -> Option<String> {
+        self.exchange.as_ref().and_then(|e| e.primary_value.clone())
+    }
+
+    /// Trait that drove the communication, e.g. "playfulness"
+    #[wasm_bindgen(getter)]
+    pub fn primary_trait(&self) -> Option<String> {
+        self.exchange.as_ref().and_then(|e| e.primary_trait.clone())
+    }
+
+    /// How intense the communication was (0-100)
+    #[wasm_bindgen(getter)]
+    pub fn intensity(&self) -> Option<u32> {
+        self.exchange.as_ref().map(|e| e.intensity)
+    }
+
+    /// What dragon1 said
+    #[wasm_bindgen(getter)]
+    pub fn content(&self) -> Option<String> {
+        self.exchange.as_ref().map(|e| e.content.clone())
+    }
+
+    /// One of "positive", "neutral", "negative", "warm" or "challenging"
+    #[wasm_bindgen(getter)]
+    pub fn response_tone(&self) -> Option<String> {
+        self.exchange.as_ref().map(|e| e.response_tone.clone())
+    }
+
+    /// How dragon2 read the communication
+    #[wasm_bindgen(getter)]
+    pub fn interpretation(&self) -> Option<String> {
+        self.exchange.as_ref().map(|e| e.interpretation.clone())
+    }
+
+    /// What dragon2 said back
+    #[wasm_bindgen(getter)]
+    pub fn response(&self) -> Option<String> {
+        self.exchange.as_ref().map(|e| e.response.clone())
+    }
+
+    /// How dragon1's opinion of dragon2 changed from the response
+    /// opinion_change is the same for dragon2's opinion of dragon1
+    #[wasm_bindgen(getter)]
+    pub fn sender_opinion_change(&self) -> i32 {
+        self.exchange.as_ref().map(|e| e.sender_opinion_change).unwrap_or(0)
+    }
+
+    /// dragon1's opinion of dragon2 before the interaction
+    /// The opinion getters are None for abilities that don't target another dragon
+    #[wasm_bindgen(getter)]
+    pub fn dragon1_opinion_before(&self) -> Option<i32> {
+        self.opinions.map(|o| o.sender_before)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn dragon1_opinion_after(&self) -> Option<i32> {
+        self.opinions.map(|o| o.sender_after)
+    }
+
+    /// dragon2's opinion of dragon1 before the interaction
+    #[wasm_bindgen(getter)]
+    pub fn dragon2_opinion_before(&self) -> Option<i32> {
+        self.opinions.map(|o| o.receiver_before)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn dragon2_opinion_after(&self) -> Option<i32> {
+        self.opinions.map(|o| o.receiver_after)
+    }
+}
+
+impl InteractionEvent {
+    fn from_interaction(interaction: &InteractionWithIndices) -> Self {
+        let result = &interaction.result;
+        let exchange = if result.ability().is_some() {
+            None
+        } else {
+            let communication = result.communication();
+            Some(ExchangeDetails {
+                comm_type: communication.comm_type.as_str().to_string(),
+                primary_value: communication.primary_value.clone(),
+                primary_trait: communication.primary_trait.clone(),
+                intensity: communication.intensity,
+                content: communication.content.clone(),
+                response_tone: result.response_tone().as_str().to_string(),
+                interpretation: result.interpretation().to_string(),
+                response: result.response_content().to_string(),
+                sender_opinion_change: result.sender_opinion_change(),
+            })
+        };
+        InteractionEvent {
+            description: result.description(),
+            dragon1_index: interaction.dragon1_idx,
+            dragon2_index: interaction.dragon2_idx,
+            opinion_change: result.opinion_change(),
+            ability: result.ability().map(|kind| kind.as_str().to_string()),
+            exchange,
+            opinions: result.opinions(),
+        }
+    }
+}
+
+/// A dragon's elemental ability exposed to TypeScript
+#[wasm_bindgen]
+pub struct AbilityInfo {
+    name: String,
+    display_name: String,
+    description: String,
+    element: String,
+    cooldown_days: u32,
+    ready_in_days: u32,
+    targets_other: bool,
+}
+
+#[wasm_bindgen]
+impl AbilityInfo {
+    /// One of "intimidation display", "healing", "lair-building", "scouting", "lightning challenge" or "meditation"
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// The ability's name in the current locale
+    #[wasm_bindgen(getter)]
+    pub fn display_name(&self) -> String {
+        self.display_name.clone()
+    }
+
+    /// What the ability does, in the current locale
+    #[wasm_bindgen(getter)]
+    pub fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn element(&self) -> String {
+        self.element.clone()
+    }
+
+    /// Days the ability takes to recover after use
+    #[wasm_bindgen(getter)]
+    pub fn cooldown_days(&self) -> u32 {
+        self.cooldown_days
+    }
+
+    /// Days until the ability can be used again, 0 if it is ready
+    #[wasm_bindgen(getter)]
+    pub fn ready_in_days(&self) -> u32 {
+        self.ready_in_days
+    }
+
+    /// Whether the ability is aimed at another dragon
+    #[wasm_bindgen(getter)]
+    pub fn targets_other(&self) -> bool {
+        self.targets_other
+    }
+}
+
+/// A remembered interaction exposed to TypeScript
+#[wasm_bindgen]
+pub struct MemoryInfo {
+    other_name: String,
+    initiated: bool,
+    communication_type: String,
+    tone: String,
+    opinion_change: i32,
+    day: u32,
+    description: String,
+}
+
+#[wasm_bindgen]
+impl MemoryInfo {
+    #[wasm_bindgen(getter)]
+    pub fn other_name(&self) -> String {
+        self.other_name.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn initiated(&self) -> bool {
+        self.initiated
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn communication_type(&self) -> String {
+        self.communication_type.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn tone(&self) -> String {
+        self.tone.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn opinion_change(&self) -> i32 {
+        self.opinion_change
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn description(&self) -> String {
+        self.description.clone()
+    }
+}
+
+impl MemoryInfo {
+    fn from_memory(memory: &Memory) -> Self {
+        MemoryInfo {
+            other_name: memory.other_name.clone(),
+            initiated: memory.initiated,
+            communication_type: memory.comm_type.as_str().to_string(),
+            tone: memory.tone.as_str().to_string(),
+            opinion_change: memory.opinion_change,
+            day: memory.day,
+            description: memory.describe(),
+        }
+    }
+}
+
+/// The separate dimensions of one dragon's view of another, exposed to TypeScript
+#[wasm_bindgen]
+pub struct RelationshipDimensions {
+    affection: i32,
+    respect: i32,
+    trust: i32,
+    fear: i32,
+    description: String,
+}
+
+#[wasm_bindgen]
+impl RelationshipDimensions {
+    /// How much the dragon likes the other (the opinion, -100 to 100)
+    #[wasm_bindgen(getter)]
+    pub fn affection(&self) -> i32 {
+        self.affection
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn respect(&self) -> i32 {
+        self.respect
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn trust(&self) -> i32 {
+        self.trust
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn fear(&self) -> i32 {
+        self.fear
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn description(&self) -> String {
+        self.description.clone()
+    }
+}
+
+impl RelationshipDimensions {
+    fn from_relationship(relationship: &Relationship) -> Self {
+        RelationshipDimensions {
+            affection: relationship.opinion(),
+            respect: relationship.respect(),
+            trust: relationship.trust(),
+            fear: relationship.fear(),
+            description: relationship.get_relationship_description(),
+        }
+    }
+}
+
+/// A bond one dragon holds with another, exposed to TypeScript
+#[wasm_bindgen]
+pub struct BondInfo {
+    other_name: String,
+    bond_type: String,
+    display_name: String,
+    formed_day: u32,
+}
+
+#[wasm_bindgen]
+impl BondInfo {
+    #[wasm_bindgen(getter)]
+    pub fn other_name(&self) -> String {
+        self.other_name.clone()
+    }
+
+    /// One of "mate", "sibling", "mentor", "apprentice" or "sworn rival"
+    #[wasm_bindgen(getter)]
+    pub fn bond_type(&self) -> String {
+        self.bond_type.clone()
+    }
+
+    /// The bond's name in the current locale
+    #[wasm_bindgen(getter)]
+    pub fn display_name(&self) -> String {
+        self.display_name.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn formed_day(&self) -> u32 {
+        self.formed_day
+    }
+}
+
+impl BondInfo {
+    fn from_bond(bond: &Bond) -> Self {
+        BondInfo {
+            other_name: bond.other_name.clone(),
+            bond_type: bond.bond_type.as_str().to_string(),
+            display_name: bond.bond_type.display_name(),
+            formed_day: bond.formed_day,
+        }
+    }
+}
+
+/// One dragon's inferred estimate of another's value or trait, exposed to TypeScript
+#[wasm_bindgen]
+pub struct BeliefInfo {
+    name: String,
+    kind: String,
+    estimate: u32,
+    observations: u32,
+}
+
+#[wasm_bindgen]
+impl BeliefInfo {
+    /// Name of the value or trait, e.g. "honor" or "playfulness"
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Either "value" or "trait"
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> String {
+        self.kind.clone()
+    }
+
+    /// Estimated score (0-100)
+    #[wasm_bindgen(getter)]
+    pub fn estimate(&self) -> u32 {
+        self.estimate
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn observations(&self) -> u32 {
+        self.observations
+    }
+}
+
+impl BeliefInfo {
+    fn from_belief(name: &str, kind: &str, belief: &Belief) -> Self {
+        BeliefInfo {
+            name: name.to_string(),
+            kind: kind.to_string(),
+            estimate: belief.estimate.round() as u32,
+            observations: belief.observations,
+        }
+    }
+
+    fn from_beliefs(beliefs: &Beliefs) -> Vec<Self> {
+        let mut infos: Vec<BeliefInfo> = beliefs
+            .values()
+            .iter()
+            .map(|(name, belief)| Self::from_belief(name, "value", belief))
+            .chain(
+                beliefs
+                    .traits()
+                    .iter()
+                    .map(|(name, belief)| Self::from_belief(name, "trait", belief)),
+            )
+            .collect();
+        infos.sort_by(|a, b| a.kind.cmp(&b.kind).then(b.estimate.cmp(&a.estimate)));
+        infos
+    }
+}
+
+/// A recorded shift in one of a dragon's values, exposed to TypeScript
+#[wasm_bindgen]
+pub struct ValueShiftInfo {
+    day: u32,
+    value: String,
+    old_score: u32,
+    new_score: u32,
+    influencer: String,
+}
+
+#[wasm_bindgen]
+impl ValueShiftInfo {
+    #[wasm_bindgen(getter)]
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Name of the value that moved, e.g. "tradition"
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> String {
+        self.value.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn old_score(&self) -> u32 {
+        self.old_score
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn new_score(&self) -> u32 {
+        self.new_score
+    }
+
+    /// Name of the dragon whose influence caused the shift
+    #[wasm_bindgen(getter)]
+    pub fn influencer(&self) -> String {
+        self.influencer.clone()
+    }
+}
+
+impl ValueShiftInfo {
+    fn from_shift(shift: &ValueShift) -> Self {
+        ValueShiftInfo {
+            day: shift.day,
+            value: shift.value.clone(),
+            old_score: shift.old_score,
+            new_score: shift.new_score,
+            influencer: shift.influencer.clone(),
+        }
+    }
+}
+
+/// A summary of one day, season or year of the clan's history, exposed to TypeScript
+#[wasm_bindgen]
+pub struct ChronicleEntry {
+    period: String,
+    start_day: u32,
+    end_day: u32,
+    interaction_count: u32,
+    narrative: String,
+    highlights: Vec<String>,
+    highlight_kinds: Vec<String>,
+}
+
+#[wasm_bindgen]
+impl ChronicleEntry {
+    /// Name of the period, e.g. "Day 12", "Spring of Year 2" or "Year 2"
+    #[wasm_bindgen(getter)]
+    pub fn period(&self) -> String {
+        self.period.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn start_day(&self) -> u32 {
+        self.start_day
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn end_day(&self) -> u32 {
+        self.end_day
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn interaction_count(&self) -> u32 {
+        self.interaction_count
+    }
+
+    /// Readable account of the period
+    #[wasm_bindgen(getter)]
+    pub fn narrative(&self) -> String {
+        self.narrative.clone()
+    }
+
+    /// One sentence for each noteworthy development, oldest first
+    #[wasm_bindgen(getter)]
+    pub fn highlights(&self) -> Vec<String> {
+        self.highlights.clone()
+    }
+
+    /// Kind of each highlight: "first friendship", "rivalry formed", "mates formed",
+    /// "promise broken", "leader changed", "hatching", "departure" or "clan renamed"
+    #[wasm_bindgen(getter)]
+    pub fn highlight_kinds(&self) -> Vec<String> {
+        self.highlight_kinds.clone()
+    }
+}
+
+impl ChronicleEntry {
+    fn from_summary(summary: &ChronicleSummary) -> Self {
+        ChronicleEntry {
+            period: summary.label.clone(),
+            start_day: summary.start_day,
+            end_day: summary.end_day,
+            interaction_count: summary.interactions,
+            narrative: summary.narrative.clone(),
+            highlights: summary.events.iter().map(|e| e.describe()).collect(),
+            highlight_kinds: summary.events.iter().map(|e| e.kind.as_str().to_string()).collect(),
+        }
+    }
+}
+
+/// Affinity of one element towards another, exposed to TypeScript
+#[wasm_bindgen]
+pub struct ElementAffinityInfo {
+    sender_element: String,
+    receiver_element: String,
+    affinity: i32,
+}
+
+#[wasm_bindgen]
+impl ElementAffinityInfo {
+    #[wasm_bindgen(getter)]
+    pub fn sender_element(&self) -> String {
+        self.sender_element.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn receiver_element(&self) -> String {
+        self.receiver_element.clone()
+    }
+
+    /// How the receiver's element reacts to the sender's, from -100 (clash) to 100 (soothe)
+    #[wasm_bindgen(getter)]
+    pub fn affinity(&self) -> i32 {
+        self.affinity
+    }
+}
+
+/// Every dragon's opinion of every other and how often they have interacted, exposed to TypeScript
+/// Both matrices are row-major: entry `i * size + j` is about dragon i's view of dragon j
+#[wasm_bindgen]
+pub struct RelationshipMatrix {
+    size: usize,
+    opinions: Vec<i32>,
+    interaction_counts: Vec<u32>,
+}
+
+#[wasm_bindgen]
+impl RelationshipMatrix {
+    /// Number of dragons, the length of each row
+    #[wasm_bindgen(getter)]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Opinions (-100 to 100) as an Int32Array; the diagonal and pairs that have never met are 0
+    #[wasm_bindgen(getter)]
+    pub fn opinions(&self) -> Vec<i32> {
+        self.opinions.clone()
+    }
+
+    /// Interaction counts as a Uint32Array
+    #[wasm_bindgen(getter)]
+    pub fn interaction_counts(&self) -> Vec<u32> {
+        self.interaction_counts.clone()
+    }
+}
+
+/// Where a dragon stands in the clan's web of positive opinions, exposed to TypeScript
+#[wasm_bindgen]
+pub struct SocialCentrality {
+    index: usize,
+    name: String,
+    in_degree: f64,
+    out_degree: f64,
+    betweenness: f64,
+    eigenvector: f64,
+    community: usize,
+    isolated: bool,
+}
+
+#[wasm_bindgen]
+impl SocialCentrality {
+    #[wasm_bindgen(getter)]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// How well liked the dragon is: the others' positive opinions of it, averaged (0-1)
+    #[wasm_bindgen(getter)]
+    pub fn in_degree(&self) -> f64 {
+        self.in_degree
+    }
+
+    /// How much the dragon likes the others, averaged (0-1)
+    #[wasm_bindgen(getter)]
+    pub fn out_degree(&self) -> f64 {
+        self.out_degree
+    }
+
+    /// How often the dragon links others who are not otherwise close (0-1)
+    #[wasm_bindgen(getter)]
+    pub fn betweenness(&self) -> f64 {
+        self.betweenness
+    }
+
+    /// How close the dragon is to other well-connected dragons, with the best connected at 1
+    #[wasm_bindgen(getter)]
+    pub fn eigenvector(&self) -> f64 {
+        self.eigenvector
+    }
+
+    /// Number of the community the dragon belongs to, as in get_communities
+    #[wasm_bindgen(getter)]
+    pub fn community(&self) -> usize {
+        self.community
+    }
+
+    /// True if no dragon likes it and it likes no dragon
+    #[wasm_bindgen(getter)]
+    pub fn isolated(&self) -> bool {
+        self.isolated
+    }
+}
+
+/// A group of dragons, such as a clique of friends or a community, exposed to TypeScript
+#[wasm_bindgen]
+pub struct DragonGroup {
+    indices: Vec<u32>,
+    names: Vec<String>,
+}
+
+#[wasm_bindgen]
+impl DragonGroup {
+    /// Indices of the dragons in the group, in clan order
+    #[wasm_bindgen(getter)]
+    pub fn indices(&self) -> Vec<u32> {
+        self.indices.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn names(&self) -> Vec<String> {
+        self.names.clone()
+    }
+}
+
+impl DragonGroup {
+    fn from_indices(indices: &[usize], names: &[String]) -> Self {
+        DragonGroup {
+            indices: indices.iter().map(|&i| i as u32).collect(),
+            names: indices.iter().map(|&i| names[i].clone()).collect(),
+        }
+    }
+}
+
+/// A faction within the clan, exposed to TypeScript
+#[wasm_bindgen]
+pub struct FactionInfo {
+    id: u32,
+    name: String,
+    formed_day: u32,
+    indices: Vec<u32>,
+    names: Vec<String>,
+}
+
+#[wasm_bindgen]
+impl FactionInfo {
+    /// Stable identifier, for get_faction_history
+    #[wasm_bindgen(getter)]
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn formed_day(&self) -> u32 {
+        self.formed_day
+    }
+
+    /// Indices of the members, in clan order
+    #[wasm_bindgen(getter)]
+    pub fn indices(&self) -> Vec<u32> {
+        self.indices.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn names(&self) -> Vec<String> {
+        self.names.clone()
+    }
+}
+
+impl FactionInfo {
+    fn from_faction(faction: &Faction, dragon_names: &[String]) -> Self {
+        FactionInfo {
+            id: faction.id,
+            name: faction.name.clone(),
+            formed_day: faction.formed_day,
+            indices: faction
+                .members
+                .iter()
+                .filter_map(|member| dragon_names.iter().position(|name| name == member))
+                .map(|i| i as u32)
+                .collect(),
+            names: faction.members.clone(),
+        }
+    }
+}
+
+/// A faction's members from the day they changed, exposed to TypeScript
+#[wasm_bindgen]
+pub struct FactionMembership {
+    day: u32,
+    names: Vec<String>,
+}
+
+#[wasm_bindgen]
+impl FactionMembership {
+    #[wasm_bindgen(getter)]
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn names(&self) -> Vec<String> {
+        self.names.clone()
+    }
+}
+
+/// A recorded series of values over time, for drawing a line chart
+/// times and values are parallel arrays, oldest first; times are in days, e.g. 3.5 for halfway through day 3
+#[wasm_bindgen]
+pub struct TimeSeries {
+    label: String,
+    times: Vec<f64>,
+    values: Vec<f64>,
+}
+
+#[wasm_bindgen]
+impl TimeSeries {
+    /// What the series measures, e.g. "average_opinion", or the other dragon's name for an opinion series
+    #[wasm_bindgen(getter)]
+    pub fn label(&self) -> String {
+        self.label.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn times(&self) -> Vec<f64> {
+        self.times.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn values(&self) -> Vec<f64> {
+        self.values.clone()
+    }
+}
+
+impl TimeSeries {
+    fn from_samples<T>(label: &str, samples: &[(f64, T)], value: impl Fn(&T) -> f64) -> Self {
+        TimeSeries {
+            label: label.to_string(),
+            times: samples.iter().map(|(time, _)| *time).collect(),
+            values: samples.iter().map(|(_, sample)| value(sample)).collect(),
+        }
+    }
+}
+
+/// How often each label occurs, e.g. how many dragons there are of each element
+/// labels and counts are parallel arrays, sorted by label
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct Tally {
+    labels: Vec<String>,
+    counts: Vec<u32>,
+}
+
+#[wasm_bindgen]
+impl Tally {
+    #[wasm_bindgen(getter)]
+    pub fn labels(&self) -> Vec<String> {
+        self.labels.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn counts(&self) -> Vec<u32> {
+        self.counts.clone()
+    }
+}
+
+impl From<Vec<(String, u32)>> for Tally {
+    fn from(entries: Vec<(String, u32)>) -> Self {
+        let (labels, counts) = entries.into_iter().unzip();
+        Tally { labels, counts }
+    }
 }
 
 /// Clan statistics exposed to TypeScript
@@ -86,18 +951,116 @@ impl InteractionEvent {
 pub struct ClanStats {
     name: String,
     dragon_count: usize,
+    lair_strength: u32,
+    scouted_territory: u32,
+    morale: i32,
+    cohesion: Cohesion,
+    mood: f64,
+    relationship_descriptions: Tally,
+    elements: Tally,
+    interaction_styles: Tally,
+    communication_types: Tally,
+    communication_tones: Tally,
 }
 
-#[wasm_bindgen]
-impl ClanStats {
+#[wasm_bindgen]
+impl ClanStats {
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn dragon_count(&self) -> usize {
+        self.dragon_count
+    }
+
+    /// How well-built the clan's lair is (0-100)
+    #[wasm_bindgen(getter)]
+    pub fn lair_strength(&self) -> u32 {
+        self.lair_strength
+    }
+
+    /// How much of the surrounding territory has been scouted (0-100)
+    #[wasm_bindgen(getter)]
+    pub fn scouted_territory(&self) -> u32 {
+        self.scouted_territory
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn morale(&self) -> i32 {
+        self.morale
+    }
+
+    /// Mean opinion across every relationship (-100 to 100)
+    #[wasm_bindgen(getter)]
+    pub fn average_opinion(&self) -> f64 {
+        self.cohesion.average_opinion
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn opinion_variance(&self) -> f64 {
+        self.cohesion.opinion_variance
+    }
+
+    /// Share of opinions that are strongly for or against (0-1)
+    #[wasm_bindgen(getter)]
+    pub fn polarization(&self) -> f64 {
+        self.cohesion.polarization
+    }
+
+    /// Pairs who both count each other as friends
+    #[wasm_bindgen(getter)]
+    pub fn friendship_count(&self) -> u32 {
+        self.cohesion.friendships
+    }
+
+    /// Pairs who both count each other as rivals
+    #[wasm_bindgen(getter)]
+    pub fn rivalry_count(&self) -> u32 {
+        self.cohesion.rivalries
+    }
+
+    /// Share of positive opinions that are returned (0-1)
+    #[wasm_bindgen(getter)]
+    pub fn reciprocity(&self) -> f64 {
+        self.cohesion.reciprocity
+    }
+
+    /// How well communications have been received, from -1 (all badly) to 1 (all well)
+    #[wasm_bindgen(getter)]
+    pub fn mood(&self) -> f64 {
+        self.mood
+    }
+
+    /// Relationships by description, e.g. "trusted_friend" or "rivals"
+    #[wasm_bindgen(getter)]
+    pub fn relationship_descriptions(&self) -> Tally {
+        self.relationship_descriptions.clone()
+    }
+
+    /// Dragons by primary element
+    #[wasm_bindgen(getter)]
+    pub fn elements(&self) -> Tally {
+        self.elements.clone()
+    }
+
+    /// Dragons by interaction style, e.g. "playful"
+    #[wasm_bindgen(getter)]
+    pub fn interaction_styles(&self) -> Tally {
+        self.interaction_styles.clone()
+    }
+
+    /// Communications by type
     #[wasm_bindgen(getter)]
-    pub fn name(&self) -> String {
-        self.name.clone()
+    pub fn communication_types(&self) -> Tally {
+        self.communication_types.clone()
     }
 
+    /// Communications by the tone of the response to them
     #[wasm_bindgen(getter)]
-    pub fn dragon_count(&self) -> usize {
-        self.dragon_count
+    pub fn communication_tones(&self) -> Tally {
+        self.communication_tones.clone()
     }
 }
 
@@ -106,25 +1069,56 @@ impl ClanStats {
 #[wasm_bindgen]
 pub struct ClanService {
     clan: Option<DragonClan>,
+    name_method: NameMethod,
+    name_style: String,
+    auto_rename: bool,
+    recorder: RecorderConfig,
+    log_capacity: usize,
+    opinion_decay: OpinionDecay,
+    trait_development: TraitDevelopmentConfig,
+}
+
+impl Default for ClanService {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[wasm_bindgen]
 impl ClanService {
     #[wasm_bindgen(constructor)]
     pub fn new() -> Self {
-        ClanService { clan: None }
+        ClanService {
+            clan: None,
+            name_method: NameMethod::default(),
+            name_style: DEFAULT_NAME_STYLE.to_string(),
+            auto_rename: false,
+            recorder: RecorderConfig::default(),
+            log_capacity: DEFAULT_LOG_CAPACITY,
+            opinion_decay: OpinionDecay::default(),
+            trait_development: TraitDevelopmentConfig::default(),
+        }
     }
 
-    /// Create a new clan with random name and initial dragons
+    /// Create a new clan with initial dragons, named after their dominant element and value
+    /// A clan created without dragons gets a random name
     pub fn create_clan(&mut self, initial_dragon_count: usize) {
-        let clan_name = generate_clan_name();
-        let mut clan = DragonClan::new(clan_name.clone());
+        let mut clan = DragonClan::new(name_generator::random_clan_name(&self.name_style));
+        clan.set_name_method(self.name_method);
+        clan.set_name_style(&self.name_style);
+        clan.set_auto_rename(self.auto_rename);
+        clan.configure_recorder(self.recorder);
+        clan.set_log_capacity(self.log_capacity);
+        clan.set_opinion_decay(self.opinion_decay);
+        clan.set_trait_development(self.trait_development);
 
         // Add initial dragons
         for _ in 0..initial_dragon_count {
-            let dragon = Self::create_random_dragon();
+            let dragon = self.create_random_dragon();
             clan.add_dragon(dragon);
         }
+        clan.name_from_composition();
+        let clan_name = clan.name();
 
         self.clan = Some(clan);
 
@@ -138,9 +1132,22 @@ impl ClanService {
 
     /// Get clan statistics
     pub fn get_clan_stats(&self) -> Option<ClanStats> {
-        self.clan.as_ref().map(|clan| ClanStats {
-            name: clan.name(),
-            dragon_count: clan.get_dragon_count(),
+        self.clan.as_ref().map(|clan| {
+            let state = clan.state();
+            ClanStats {
+                name: clan.name(),
+                dragon_count: clan.get_dragon_count(),
+                lair_strength: state.lair_strength,
+                scouted_territory: state.scouted_territory,
+                morale: state.morale,
+                cohesion: clan.cohesion(),
+                mood: clan.communications().mood(),
+                relationship_descriptions: clan.relationship_labels().into(),
+                elements: clan.element_distribution().into(),
+                interaction_styles: clan.interaction_style_distribution().into(),
+                communication_types: clan.communications().types().into(),
+                communication_tones: clan.communications().tones().into(),
+            }
         })
     }
 
@@ -183,8 +1190,8 @@ impl ClanService {
 
     /// Add a random dragon to the clan
     pub fn add_random_dragon(&mut self) -> Option<DragonInfo> {
+        let dragon = self.create_random_dragon();
         let clan = self.clan.as_mut()?;
-        let dragon = Self::create_random_dragon();
         let dragon_info = DragonInfo::from_dragon(&dragon);
         clan.add_dragon(dragon);
         
@@ -249,24 +1256,20 @@ impl ClanService {
             return None;
         }
 
+        let old_name = clan.name();
         let interactions = clan.simulate_interactions_with_indices(1);
         if interactions.is_empty() {
             return None;
         }
 
         let interaction = &interactions[0];
-        let event = InteractionEvent {
-            description: interaction.result.description(),
-            dragon1_index: interaction.dragon1_idx,
-            dragon2_index: interaction.dragon2_idx,
-            opinion_change: interaction.result.opinion_change(),
-        };
+        let event = InteractionEvent::from_interaction(interaction);
         
         // Emit event
-        let event_data = js_sys::Object::new();
-        js_sys::Reflect::set(&event_data, &"type".into(), &"interaction-simulated".into()).unwrap();
-        js_sys::Reflect::set(&event_data, &"event".into(), &Self::interaction_event_to_js_value(&event)).unwrap();
-        notification::get_notification_service().emit(notification::EventType::InteractionSimulated, &event_data.into());
+        Self::emit_interaction(&event);
+        Self::emit_bond_formed(clan, interaction);
+        Self::emit_clan_renamed(clan, &old_name);
+        Self::emit_faction_events(clan);
         
         Some(event)
     }
@@ -282,51 +1285,52 @@ impl ClanService {
             return Vec::new();
         }
 
+        let old_name = clan.name();
         let interactions = clan.simulate_interactions_with_indices(count);
         
         let events: Vec<InteractionEvent> = interactions
             .iter()
-            .map(|interaction| {
-                InteractionEvent {
-                    description: interaction.result.description(),
-                    dragon1_index: interaction.dragon1_idx,
-                    dragon2_index: interaction.dragon2_idx,
-                    opinion_change: interaction.result.opinion_change(),
-                }
-            })
+            .map(InteractionEvent::from_interaction)
             .collect();
         
         // Emit events for each interaction
         for event in &events {
-            let event_data = js_sys::Object::new();
-            js_sys::Reflect::set(&event_data, &"type".into(), &"interaction-simulated".into()).unwrap();
-            js_sys::Reflect::set(&event_data, &"event".into(), &Self::interaction_event_to_js_value(event)).unwrap();
-            notification::get_notification_service().emit(notification::EventType::InteractionSimulated, &event_data.into());
+            Self::emit_interaction(event);
+        }
+        for interaction in &interactions {
+            Self::emit_bond_formed(clan, interaction);
         }
+        Self::emit_clan_renamed(clan, &old_name);
+        Self::emit_faction_events(clan);
         
         events
     }
 
     /// Reset the clan (clear and create new)
     pub fn reset_clan(&mut self, initial_dragon_count: usize) {
-        let new_name = if let Some(clan) = &mut self.clan {
+        if let Some(clan) = &mut self.clan {
             clan.clear();
-            let name = generate_clan_name();
-            clan.set_name(name.clone());
-            name
+            clan.set_name(name_generator::random_clan_name(&self.name_style));
         } else {
             self.create_clan(initial_dragon_count);
             return;
-        };
+        }
 
         // Add new dragons
         for _ in 0..initial_dragon_count {
-            let dragon = Self::create_random_dragon();
+            let dragon = self.create_random_dragon();
             if let Some(clan) = &mut self.clan {
                 clan.add_dragon(dragon);
             }
         }
-        
+        let new_name = match &mut self.clan {
+            Some(clan) => {
+                clan.name_from_composition();
+                clan.name()
+            }
+            None => return,
+        };
+
         // Emit event
         let event_data = js_sys::Object::new();
         js_sys::Reflect::set(&event_data, &"type".into(), &"clan-reset".into()).unwrap();
@@ -349,6 +1353,205 @@ impl ClanService {
         clan.get_opinion_by_indices(dragon1_index, dragon2_index)
     }
 
+    /// Get every dragon's opinion of every other, and their interaction counts, in a single call
+    /// Use this rather than calling get_opinion for each pair
+    pub fn get_relationship_matrix(&self) -> Option<RelationshipMatrix> {
+        let clan = self.clan.as_ref()?;
+        let (opinions, interaction_counts) = clan.relationship_matrices();
+        Some(RelationshipMatrix {
+            size: clan.get_dragon_count(),
+            opinions,
+            interaction_counts,
+        })
+    }
+
+    /// Get each dragon's place in the clan's social network, treating positive opinions as ties:
+    /// how popular, central and well connected it is, its community, and whether it is isolated
+    pub fn get_social_centrality(&self) -> Vec<SocialCentrality> {
+        let clan = match self.clan.as_ref() {
+            Some(clan) => clan,
+            None => return Vec::new(),
+        };
+        let graph = clan.social_graph();
+        let in_degree = graph.in_degree();
+        let out_degree = graph.out_degree();
+        let betweenness = graph.betweenness();
+        let eigenvector = graph.eigenvector();
+        let communities = graph.communities();
+        let isolated = graph.isolated();
+        clan.dragon_names()
+            .into_iter()
+            .enumerate()
+            .map(|(index, name)| SocialCentrality {
+                index,
+                name,
+                in_degree: in_degree[index],
+                out_degree: out_degree[index],
+                betweenness: betweenness[index],
+                eigenvector: eigenvector[index],
+                community: communities[index],
+                isolated: isolated[index],
+            })
+            .collect()
+    }
+
+    /// Get the groups of three or more dragons who are all friends with each other, largest first
+    pub fn get_friend_cliques(&self) -> Vec<DragonGroup> {
+        let clan = match self.clan.as_ref() {
+            Some(clan) => clan,
+            None => return Vec::new(),
+        };
+        let names = clan.dragon_names();
+        clan.social_graph()
+            .friend_cliques()
+            .iter()
+            .map(|clique| DragonGroup::from_indices(clique, &names))
+            .collect()
+    }
+
+    /// Get the communities the clan's ties divide it into, numbered as in get_social_centrality
+    /// Isolated dragons form communities of their own
+    pub fn get_communities(&self) -> Vec<DragonGroup> {
+        let clan = match self.clan.as_ref() {
+            Some(clan) => clan,
+            None => return Vec::new(),
+        };
+        let names = clan.dragon_names();
+        let communities = clan.social_graph().communities();
+        let count = communities.iter().max().map_or(0, |max| max + 1);
+        (0..count)
+            .map(|community| {
+                let members: Vec<usize> = (0..names.len()).filter(|&i| communities[i] == community).collect();
+                DragonGroup::from_indices(&members, &names)
+            })
+            .collect()
+    }
+
+    /// Export the interaction log, oldest first, as "csv" (with a header row) or "jsonl" (one JSON object per line)
+    /// Each record has sequence, time (in days), day, sender, receiver, comm_type, ability, primary_value,
+    /// primary_trait, tone, intensity, sender_opinion_change and receiver_opinion_change
+    pub fn export_log(&self, format: String) -> Result<String, String> {
+        let format = LogFormat::from_str(&format)
+            .ok_or_else(|| format!("unknown log format '{}', expected csv or jsonl", format))?;
+        Ok(match &self.clan {
+            Some(clan) => clan.log().export(format),
+            None => InteractionLog::default().export(format),
+        })
+    }
+
+    /// Set how many of the latest interactions the log keeps (at least 1)
+    pub fn set_log_capacity(&mut self, capacity: usize) {
+        self.log_capacity = capacity.max(1);
+        if let Some(clan) = &mut self.clan {
+            clan.set_log_capacity(self.log_capacity);
+        }
+    }
+
+    /// Configure the recorder that samples opinions and clan metrics as the clan clock ticks
+    /// Each interaction is a tick, as is each day passed with advance_days
+    /// capacity: samples kept per series before the oldest are dropped
+    /// interval: ticks between samples
+    pub fn configure_recorder(&mut self, enabled: bool, capacity: usize, interval: u32) {
+        self.recorder = RecorderConfig {
+            enabled,
+            capacity,
+            interval,
+        };
+        if let Some(clan) = &mut self.clan {
+            clan.configure_recorder(self.recorder);
+        }
+    }
+
+    /// Get how dragon1's opinion of dragon2 has changed over the recorded ticks, labelled with dragon2's name
+    pub fn get_opinion_series(&self, dragon1_index: usize, dragon2_index: usize) -> Option<TimeSeries> {
+        let clan = self.clan.as_ref()?;
+        let holder = clan.get_dragon(dragon1_index)?.name();
+        let subject = clan.get_dragon(dragon2_index)?.name();
+        let samples = clan.recorder().pair_series(&holder, &subject);
+        Some(TimeSeries::from_samples(&subject, &samples, |&opinion| opinion as f64))
+    }
+
+    /// Get a dragon's recorded standing: "opinion_given" (its average opinion of the others)
+    /// and "opinion_received" (their average opinion of it)
+    pub fn get_dragon_series(&self, index: usize) -> Vec<TimeSeries> {
+        let clan = match self.clan.as_ref() {
+            Some(clan) => clan,
+            None => return Vec::new(),
+        };
+        let samples = match clan.get_dragon(index) {
+            Some(dragon) => clan.recorder().dragon_series(&dragon.name()),
+            None => return Vec::new(),
+        };
+        vec![
+            TimeSeries::from_samples("opinion_given", &samples, |metrics| metrics.opinion_given),
+            TimeSeries::from_samples("opinion_received", &samples, |metrics| metrics.opinion_received),
+        ]
+    }
+
+    /// Get the recorded clan metrics, one series each: dragon_count, average_opinion, polarization,
+    /// reciprocity, friendships, rivalries, mood and morale
+    pub fn get_clan_series(&self) -> Vec<TimeSeries> {
+        let samples = match self.clan.as_ref() {
+            Some(clan) => clan.recorder().clan_series(),
+            None => return Vec::new(),
+        };
+        vec![
+            TimeSeries::from_samples("dragon_count", &samples, |m| m.dragon_count as f64),
+            TimeSeries::from_samples("average_opinion", &samples, |m| m.average_opinion),
+            TimeSeries::from_samples("polarization", &samples, |m| m.polarization),
+            TimeSeries::from_samples("reciprocity", &samples, |m| m.reciprocity),
+            TimeSeries::from_samples("friendships", &samples, |m| m.friendships as f64),
+            TimeSeries::from_samples("rivalries", &samples, |m| m.rivalries as f64),
+            TimeSeries::from_samples("mood", &samples, |m| m.mood),
+            TimeSeries::from_samples("morale", &samples, |m| m.morale as f64),
+        ]
+    }
+
+    /// Get the factions the clan has divided into, oldest first
+    /// Factions are groups of three or more that think well of each other and less of the rest,
+    /// and are re-detected at the start of each day
+    pub fn get_factions(&self) -> Vec<FactionInfo> {
+        let clan = match self.clan.as_ref() {
+            Some(clan) => clan,
+            None => return Vec::new(),
+        };
+        let names = clan.dragon_names();
+        clan.factions().iter().map(|faction| FactionInfo::from_faction(faction, &names)).collect()
+    }
+
+    /// Get a faction's membership each time it changed, starting with its founders
+    /// Returns an empty list if no current faction has the id
+    pub fn get_faction_history(&self, faction_id: u32) -> Vec<FactionMembership> {
+        self.clan
+            .as_ref()
+            .and_then(|clan| clan.faction(faction_id))
+            .map(|faction| {
+                faction
+                    .history
+                    .iter()
+                    .map(|(day, names)| FactionMembership {
+                        day: *day,
+                        names: names.clone(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Get affection, respect, trust and fear of dragon1 towards dragon2
+    /// Returns a neutral relationship if the dragons have not interacted yet
+    pub fn get_relationship_dimensions(&self, dragon1_index: usize, dragon2_index: usize) -> Option<RelationshipDimensions> {
+        let clan = self.clan.as_ref()?;
+        if dragon1_index >= clan.get_dragon_count() || dragon2_index >= clan.get_dragon_count() || dragon1_index == dragon2_index {
+            return None;
+        }
+        let dimensions = match clan.get_relationship_by_indices(dragon1_index, dragon2_index) {
+            Some(relationship) => RelationshipDimensions::from_relationship(relationship),
+            None => RelationshipDimensions::from_relationship(&Relationship::new()),
+        };
+        Some(dimensions)
+    }
+
     /// Get character info for a dragon
     pub fn get_dragon_character_info(&self, index: usize) -> Option<String> {
         let clan = self.clan.as_ref()?;
@@ -356,24 +1559,441 @@ impl ClanService {
         Some(dragon.get_character_info())
     }
 
-    /// Helper to create a random dragon
-    fn create_random_dragon() -> Dragon {
-        let elements = [
-            DragonElement::Fire,
-            DragonElement::Water,
-            DragonElement::Earth,
-            DragonElement::Wind,
-            DragonElement::Lightning,
-            DragonElement::Ice,
-        ];
-        
+    /// Get the current day on the clan clock
+    pub fn get_day(&self) -> u32 {
+        self.clan.as_ref().map(|clan| clan.day()).unwrap_or(0)
+    }
+
+    /// Choose how new dragons are named: "syllable" (the default) or "markov"
+    /// Markov names are invented from letter patterns learned from each element's seed names
+    pub fn set_name_method(&mut self, method: String) -> Result<(), String> {
+        let method = NameMethod::from_str(&method)
+            .ok_or_else(|| format!("unknown name method '{}', expected syllable or markov", method))?;
+        self.name_method = method;
+        if let Some(clan) = &mut self.clan {
+            clan.set_name_method(method);
+        }
+        Ok(())
+    }
+
+    pub fn get_name_method(&self) -> String {
+        self.name_method.as_str().to_string()
+    }
+
+    /// Choose the name style pack for new dragons and clan names, e.g. "classic", "norse",
+    /// "draconic-harsh" or "elvish-soft"
+    /// The current clan keeps its name until it is renamed
+    pub fn set_name_style(&mut self, style: String) -> Result<(), String> {
+        if !name_style::is_known(&style) {
+            return Err(format!(
+                "unknown name style '{}', expected one of {}",
+                style,
+                name_style::style_names().join(", ")
+            ));
+        }
+        if let Some(clan) = &mut self.clan {
+            clan.set_name_style(&style);
+        }
+        self.name_style = style;
+        Ok(())
+    }
+
+    pub fn get_name_style(&self) -> String {
+        self.name_style.clone()
+    }
+
+    /// Get the names of all registered name styles
+    pub fn get_name_styles(&self) -> Vec<String> {
+        name_style::style_names()
+    }
+
+    /// Register name styles from JSON of the form {"styles": [...]}
+    /// Styles with an existing name replace the old pack; returns the number of styles loaded
+    pub fn load_name_styles(&mut self, json: String) -> Result<usize, String> {
+        name_style::load_name_styles(&json)
+    }
+
+    /// Restore the built-in name styles
+    /// A custom style in use is replaced by the classic style
+    pub fn reset_name_styles(&mut self) {
+        name_style::reset_name_styles();
+        if !name_style::is_known(&self.name_style) {
+            self.name_style = DEFAULT_NAME_STYLE.to_string();
+            if let Some(clan) = &mut self.clan {
+                clan.set_name_style(DEFAULT_NAME_STYLE);
+            }
+        }
+    }
+
+    /// Let the clan take a new name when its composition shifts: a new dominant element renames it,
+    /// and a new favourite value re-titles it. Checked each night; clans named by hand keep their name
+    pub fn set_auto_rename(&mut self, enabled: bool) {
+        self.auto_rename = enabled;
+        if let Some(clan) = &mut self.clan {
+            clan.set_auto_rename(enabled);
+        }
+    }
+
+    pub fn get_auto_rename(&self) -> bool {
+        self.auto_rename
+    }
+
+    /// Name the clan after its current dominant element and value, e.g. "The Frozen Council"
+    /// Returns the new name, or None if there is no clan or it has no dragons
+    pub fn name_clan_from_composition(&mut self) -> Option<String> {
+        let clan = self.clan.as_mut()?;
+        if clan.name_from_composition() {
+            Some(clan.name())
+        } else {
+            None
+        }
+    }
+
+    /// Get the clan's history summarized by "day", "season" or "year", oldest first
+    /// Periods in which nothing happened are left out
+    pub fn get_chronicle(&self, range: String) -> Result<Vec<ChronicleEntry>, String> {
+        let period = ChroniclePeriod::from_str(&range)
+            .ok_or_else(|| format!("unknown chronicle range '{}', expected day, season or year", range))?;
+        Ok(self
+            .clan
+            .as_ref()
+            .map(|clan| {
+                clan.chronicle()
+                    .summaries(period, clan.day())
+                    .iter()
+                    .map(ChronicleEntry::from_summary)
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Let days pass without interactions, so opinions drift toward neutral
+    pub fn advance_days(&mut self, days: u32) {
+        if let Some(clan) = &mut self.clan {
+            let old_name = clan.name();
+            clan.advance_days(days);
+            Self::emit_clan_renamed(clan, &old_name);
+            Self::emit_faction_events(clan);
+        }
+    }
+
+    /// Configure how quickly opinions drift toward neutral without contact
+    /// half_life_days: days for an opinion to fall halfway to neutral
+    /// bond_factor: half-life multiplier for bonded dragons
+    /// grudge_factor: half-life multiplier for grudges held by impatient dragons
+    /// Every setting must be finite and at least 0; a half-life of 0 turns decay off
+    pub fn set_opinion_decay(&mut self, half_life_days: f64, bond_factor: f64, grudge_factor: f64) -> Result<(), String> {
+        self.opinion_decay = OpinionDecay::new(half_life_days, bond_factor, grudge_factor)?;
+        if let Some(clan) = &mut self.clan {
+            clan.set_opinion_decay(self.opinion_decay);
+        }
+        Ok(())
+    }
+
+    /// Configure how dragons' traits develop with age and experience
+    /// rejections_per_point: rejected communications needed to lose a point of sociability
+    /// leadership_per_point: well-received leads over less dominant dragons needed to gain a point of dominance
+    /// patience_per_year: patience gained each year, at most 100
+    /// max_change: furthest any trait can develop from where it started, at most 100
+    pub fn set_trait_development(
+        &mut self,
+        rejections_per_point: u32,
+        leadership_per_point: u32,
+        patience_per_year: u32,
+        max_change: u32,
+    ) {
+        self.trait_development = TraitDevelopmentConfig {
+            rejections_per_point,
+            leadership_per_point,
+            patience_per_year: patience_per_year.min(100),
+            max_change: max_change.min(100),
+        };
+        if let Some(clan) = &mut self.clan {
+            clan.set_trait_development(self.trait_development);
+        }
+    }
+
+    /// Get the full element affinity matrix, one entry per sender/receiver pair
+    pub fn get_element_affinities(&self) -> Vec<ElementAffinityInfo> {
+        let elements = element::element_names();
+        let mut infos = Vec::new();
+        for sender in &elements {
+            for receiver in &elements {
+                infos.push(ElementAffinityInfo {
+                    sender_element: sender.clone(),
+                    receiver_element: receiver.clone(),
+                    affinity: affinity::get_affinity(sender, receiver),
+                });
+            }
+        }
+        infos
+    }
+
+    /// Get how a dragon of receiver_element reacts to a dragon of sender_element
+    /// Returns None if either element is unknown
+    pub fn get_element_affinity(&self, sender_element: String, receiver_element: String) -> Option<i32> {
+        if element::is_known(&sender_element) && element::is_known(&receiver_element) {
+            Some(affinity::get_affinity(&sender_element, &receiver_element))
+        } else {
+            None
+        }
+    }
+
+    /// Change how a dragon of receiver_element reacts to a dragon of sender_element (-100 to 100)
+    /// The matrix is shared by every clan, like the element registry it is built from
+    /// Returns false if either element is unknown
+    pub fn set_element_affinity(&mut self, sender_element: String, receiver_element: String, value: i32) -> bool {
+        if element::is_known(&sender_element) && element::is_known(&receiver_element) {
+            affinity::set_affinity(&sender_element, &receiver_element, value);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Restore the element affinity matrix defined by the element registry, for every clan
+    pub fn reset_element_affinities(&mut self) {
+        affinity::reset_affinities();
+    }
+
+    /// Get the names of all registered elements
+    pub fn get_elements(&self) -> Vec<String> {
+        element::element_names()
+    }
+
+    /// Register elements from JSON of the form {"elements": [...]}
+    /// Elements with an existing name replace the old definition
+    /// Only the loaded elements' affinities are taken from the JSON; other pairs keep any changes made to them
+    /// Returns the number of elements loaded
+    pub fn load_elements(&mut self, json: String) -> Result<usize, String> {
+        let names = element::load_elements(&json)?;
+        affinity::refresh_elements(&names);
+        Ok(names.len())
+    }
+
+    /// Load communication templates from JSON of the form {"templates": {"key": [{"text": "...", "weight": 1}]}}
+    /// into the current locale's catalog
+    /// Keys it defines replace the built-in variants for that key; other keys keep their defaults
+    /// Returns the number of keys loaded
+    pub fn load_templates(&mut self, json: String) -> Result<usize, String> {
+        locale::load_catalog(&locale::current_locale(), &json)
+    }
+
+    /// Restore the built-in communication templates and message catalogs
+    pub fn reset_templates(&mut self) {
+        locale::reset_catalogs();
+    }
+
+    /// Switch the language of all generated text, e.g. "en" or "es"
+    /// Fails if there is no catalog for the locale
+    pub fn set_locale(&mut self, locale: String) -> Result<(), String> {
+        locale::set_locale(&locale)
+    }
+
+    pub fn get_locale(&self) -> String {
+        locale::current_locale()
+    }
+
+    /// Locales that have a message catalog
+    pub fn get_locales(&self) -> Vec<String> {
+        locale::locales()
+    }
+
+    /// Load a message catalog for a locale, in the same format as load_templates
+    /// Adds a new locale, or replaces the keys it defines in an existing one
+    /// Keys missing from a catalog fall back to English
+    /// Returns the number of keys loaded
+    pub fn load_locale(&mut self, locale: String, json: String) -> Result<usize, String> {
+        locale::load_catalog(&locale, &json)
+    }
+
+    /// Restore the built-in elements and their affinities
+    pub fn reset_elements(&mut self) {
+        element::reset_elements();
+        affinity::reset_affinities();
+    }
+
+    /// Get the notable interactions a dragon remembers, oldest first
+    pub fn get_memories(&self, index: usize) -> Vec<MemoryInfo> {
+        self.clan
+            .as_ref()
+            .and_then(|clan| clan.get_memories_by_index(index))
+            .map(|memories| memories.iter().map(MemoryInfo::from_memory).collect())
+            .unwrap_or_default()
+    }
+
+    /// Get what dragon1 remembers about dragon2, oldest first
+    /// Useful for explaining why dragon1 feels the way it does about dragon2
+    pub fn get_memories_about(&self, dragon1_index: usize, dragon2_index: usize) -> Vec<MemoryInfo> {
+        self.clan
+            .as_ref()
+            .and_then(|clan| clan.get_memories_about_by_indices(dragon1_index, dragon2_index))
+            .map(|memories| memories.into_iter().map(MemoryInfo::from_memory).collect())
+            .unwrap_or_default()
+    }
+
+    /// Get what dragon1 has inferred about dragon2's values and traits
+    /// Beliefs are built only from observed communications and responses
+    pub fn get_beliefs_about(&self, dragon1_index: usize, dragon2_index: usize) -> Vec<BeliefInfo> {
+        self.clan
+            .as_ref()
+            .and_then(|clan| clan.get_beliefs_by_indices(dragon1_index, dragon2_index))
+            .map(BeliefInfo::from_beliefs)
+            .unwrap_or_default()
+    }
+
+    /// Get how a dragon's values have drifted over time, oldest first
+    pub fn get_value_history(&self, index: usize) -> Vec<ValueShiftInfo> {
+        self.clan
+            .as_ref()
+            .and_then(|clan| clan.get_value_history_by_index(index))
+            .map(|history| history.map(ValueShiftInfo::from_shift).collect())
+            .unwrap_or_default()
+    }
+
+    /// Get the bonds a dragon holds, in the order they formed
+    pub fn get_bonds(&self, index: usize) -> Vec<BondInfo> {
+        self.clan
+            .as_ref()
+            .and_then(|clan| clan.get_bonds_by_index(index))
+            .map(|bonds| bonds.iter().map(BondInfo::from_bond).collect())
+            .unwrap_or_default()
+    }
+
+    /// Hatch an egg from two mated dragons
+    /// Returns None if the dragons are not mates
+    pub fn hatch_egg(&mut self, parent1_index: usize, parent2_index: usize) -> Option<DragonInfo> {
+        let clan = self.clan.as_mut()?;
+        let hatchling_index = clan.hatch(parent1_index, parent2_index)?;
+        let dragon_info = DragonInfo::from_dragon(&clan.get_dragon(hatchling_index)?);
+
+        // Emit event
+        let event_data = js_sys::Object::new();
+        js_sys::Reflect::set(&event_data, &"type".into(), &"dragon-added".into()).unwrap();
+        js_sys::Reflect::set(&event_data, &"dragon".into(), &Self::dragon_info_to_js_value(&dragon_info)).unwrap();
+        notification::get_notification_service().emit(notification::EventType::DragonAdded, &event_data.into());
+
+        Some(dragon_info)
+    }
+
+    /// Get a dragon's elemental ability and how long until it is ready
+    /// Returns None if the dragon's element has no ability
+    pub fn get_ability(&self, index: usize) -> Option<AbilityInfo> {
+        let clan = self.clan.as_ref()?;
+        let dragon = clan.get_dragon(index)?;
+        let kind = dragon.ability()?;
+        Some(AbilityInfo {
+            name: kind.as_str().to_string(),
+            display_name: kind.display_name(),
+            description: kind.summary(),
+            element: dragon.element(),
+            cooldown_days: kind.cooldown_days(),
+            ready_in_days: dragon.ability_cooldown(clan.day()),
+            targets_other: kind.targets_other(),
+        })
+    }
+
+    /// Have a dragon use its elemental ability
+    /// Abilities aimed at another dragon use the given target, or let the dragon choose if none is given
+    /// Returns None if the ability is cooling down or has no valid target
+    pub fn use_ability(&mut self, index: usize, target_index: Option<usize>) -> Option<InteractionEvent> {
+        let clan = self.clan.as_mut()?;
+        let old_name = clan.name();
+        let (target, result) = clan.use_ability(index, target_index)?;
+        let interaction = InteractionWithIndices {
+            dragon1_idx: index,
+            dragon2_idx: target,
+            result,
+        };
+        let event = InteractionEvent::from_interaction(&interaction);
+
+        // Emit event
+        Self::emit_interaction(&event);
+        Self::emit_bond_formed(clan, &interaction);
+        Self::emit_clan_renamed(clan, &old_name);
+        Self::emit_faction_events(clan);
+
+        Some(event)
+    }
+
+    /// Helper to emit an interaction-simulated event, or an ability-used event for an ability without a target
+    fn emit_interaction(event: &InteractionEvent) {
+        let (event_type, type_name) = match event.dragon2_index {
+            Some(_) => (notification::EventType::InteractionSimulated, "interaction-simulated"),
+            None => (notification::EventType::AbilityUsed, "ability-used"),
+        };
+        let event_data = js_sys::Object::new();
+        js_sys::Reflect::set(&event_data, &"type".into(), &type_name.into()).unwrap();
+        js_sys::Reflect::set(&event_data, &"event".into(), &Self::interaction_event_to_js_value(event)).unwrap();
+        notification::get_notification_service().emit(event_type, &event_data.into());
+    }
+
+    /// Helper to emit an event when an interaction formed a new bond
+    fn emit_bond_formed(clan: &DragonClan, interaction: &InteractionWithIndices) {
+        let (bond_type, dragon2_idx) = match (interaction.result.new_bond(), interaction.dragon2_idx) {
+            (Some(bond_type), Some(dragon2_idx)) => (bond_type, dragon2_idx),
+            _ => return,
+        };
+        let dragon1_name = clan.get_dragon(interaction.dragon1_idx).map(|d| d.name()).unwrap_or_default();
+        let dragon2_name = clan.get_dragon(dragon2_idx).map(|d| d.name()).unwrap_or_default();
+
+        let event_data = js_sys::Object::new();
+        js_sys::Reflect::set(&event_data, &"type".into(), &"bond-formed".into()).unwrap();
+        js_sys::Reflect::set(&event_data, &"dragon1Index".into(), &(interaction.dragon1_idx as u32).into()).unwrap();
+        js_sys::Reflect::set(&event_data, &"dragon2Index".into(), &(dragon2_idx as u32).into()).unwrap();
+        js_sys::Reflect::set(&event_data, &"dragon1Name".into(), &dragon1_name.into()).unwrap();
+        js_sys::Reflect::set(&event_data, &"dragon2Name".into(), &dragon2_name.into()).unwrap();
+        js_sys::Reflect::set(&event_data, &"bondType".into(), &bond_type.as_str().into()).unwrap();
+        notification::get_notification_service().emit(notification::EventType::BondFormed, &event_data.into());
+    }
+
+    /// Emit a clan-renamed event if the clan's name has changed from old_name
+    fn emit_clan_renamed(clan: &DragonClan, old_name: &str) {
+        let new_name = clan.name();
+        if new_name == old_name {
+            return;
+        }
+
+        let event_data = js_sys::Object::new();
+        js_sys::Reflect::set(&event_data, &"type".into(), &"clan-renamed".into()).unwrap();
+        js_sys::Reflect::set(&event_data, &"oldName".into(), &old_name.into()).unwrap();
+        js_sys::Reflect::set(&event_data, &"clanName".into(), &new_name.into()).unwrap();
+        notification::get_notification_service().emit(notification::EventType::ClanRenamed, &event_data.into());
+    }
+
+    /// Emit an event for each change to the clan's factions since the last call
+    fn emit_faction_events(clan: &mut DragonClan) {
+        for event in clan.take_faction_events() {
+            let (event_type, others_key) = match event.kind {
+                FactionEventKind::Formed => (notification::EventType::FactionFormed, None),
+                FactionEventKind::Split => (notification::EventType::FactionSplit, Some("splinters")),
+                FactionEventKind::Merged => (notification::EventType::FactionMerged, Some("absorbed")),
+                FactionEventKind::Dissolved => (notification::EventType::FactionDissolved, None),
+            };
+            let members: js_sys::Array = event.members.iter().map(|name| JsValue::from(name.as_str())).collect();
+
+            let event_data = js_sys::Object::new();
+            js_sys::Reflect::set(&event_data, &"type".into(), &format!("faction-{}", event.kind.as_str()).into()).unwrap();
+            js_sys::Reflect::set(&event_data, &"day".into(), &event.day.into()).unwrap();
+            js_sys::Reflect::set(&event_data, &"factionName".into(), &event.faction.into()).unwrap();
+            js_sys::Reflect::set(&event_data, &"members".into(), &members).unwrap();
+            if let Some(key) = others_key {
+                let others: js_sys::Array = event.others.iter().map(|name| JsValue::from(name.as_str())).collect();
+                js_sys::Reflect::set(&event_data, &key.into(), &others).unwrap();
+            }
+            notification::get_notification_service().emit(event_type, &event_data.into());
+        }
+    }
+
+    /// Helper to create a random dragon, named with the chosen name method
+    fn create_random_dragon(&self) -> Dragon {
+        let element = element::random_element();
+
         let mut rng = rand::thread_rng();
-        let element = elements[rng.gen_range(0..elements.len())];
-        // Use the public generate_dragon_name function
-        let name = crate::name_generator::generate_dragon_name(Some(element.as_str().to_string()));
+        let name = name_generator::generate_name(self.name_method, &self.name_style, Some(&element));
         let age = rng.gen_range(1..=15);
         
-        Dragon::new(name, element.as_str().to_string(), age)
+        Dragon::new(name, element, age)
     }
 
     /// Helper to convert DragonInfo to JsValue
@@ -381,18 +2001,57 @@ impl ClanService {
         let obj = js_sys::Object::new();
         js_sys::Reflect::set(&obj, &"name".into(), &dragon_info.name().into()).unwrap();
         js_sys::Reflect::set(&obj, &"element".into(), &dragon_info.element().into()).unwrap();
-        js_sys::Reflect::set(&obj, &"age".into(), &(dragon_info.age() as u32).into()).unwrap();
+        if let Some(secondary_element) = dragon_info.secondary_element() {
+            js_sys::Reflect::set(&obj, &"secondaryElement".into(), &secondary_element.into()).unwrap();
+        }
+        js_sys::Reflect::set(&obj, &"age".into(), &dragon_info.age().into()).unwrap();
         js_sys::Reflect::set(&obj, &"interactionStyle".into(), &dragon_info.interaction_style().into()).unwrap();
+        if let Some(epithet) = dragon_info.epithet() {
+            js_sys::Reflect::set(&obj, &"epithet".into(), &epithet.into()).unwrap();
+        }
+        js_sys::Reflect::set(&obj, &"displayName".into(), &dragon_info.display_name().into()).unwrap();
         obj.into()
     }
 
     /// Helper to convert InteractionEvent to JsValue
+    /// Abilities without a target become {description, dragonIndex, ability, opinionChange}
     fn interaction_event_to_js_value(event: &InteractionEvent) -> JsValue {
         let obj = js_sys::Object::new();
         js_sys::Reflect::set(&obj, &"description".into(), &event.description().into()).unwrap();
-        js_sys::Reflect::set(&obj, &"dragon1Index".into(), &(event.dragon1_index() as u32).into()).unwrap();
-        js_sys::Reflect::set(&obj, &"dragon2Index".into(), &(event.dragon2_index() as u32).into()).unwrap();
-        js_sys::Reflect::set(&obj, &"opinionChange".into(), &(event.opinion_change() as i32).into()).unwrap();
+        match event.dragon2_index {
+            Some(dragon2_index) => {
+                js_sys::Reflect::set(&obj, &"dragon1Index".into(), &(event.dragon1_index() as u32).into()).unwrap();
+                js_sys::Reflect::set(&obj, &"dragon2Index".into(), &(dragon2_index as u32).into()).unwrap();
+            }
+            None => {
+                js_sys::Reflect::set(&obj, &"dragonIndex".into(), &(event.dragon1_index() as u32).into()).unwrap();
+            }
+        }
+        js_sys::Reflect::set(&obj, &"opinionChange".into(), &event.opinion_change().into()).unwrap();
+        if let Some(ability) = event.ability() {
+            js_sys::Reflect::set(&obj, &"ability".into(), &ability.into()).unwrap();
+        }
+        if let Some(exchange) = &event.exchange {
+            js_sys::Reflect::set(&obj, &"commType".into(), &exchange.comm_type.clone().into()).unwrap();
+            if let Some(value) = &exchange.primary_value {
+                js_sys::Reflect::set(&obj, &"primaryValue".into(), &value.clone().into()).unwrap();
+            }
+            if let Some(trait_name) = &exchange.primary_trait {
+                js_sys::Reflect::set(&obj, &"primaryTrait".into(), &trait_name.clone().into()).unwrap();
+            }
+            js_sys::Reflect::set(&obj, &"intensity".into(), &exchange.intensity.into()).unwrap();
+            js_sys::Reflect::set(&obj, &"content".into(), &exchange.content.clone().into()).unwrap();
+            js_sys::Reflect::set(&obj, &"responseTone".into(), &exchange.response_tone.clone().into()).unwrap();
+            js_sys::Reflect::set(&obj, &"interpretation".into(), &exchange.interpretation.clone().into()).unwrap();
+            js_sys::Reflect::set(&obj, &"response".into(), &exchange.response.clone().into()).unwrap();
+            js_sys::Reflect::set(&obj, &"senderOpinionChange".into(), &exchange.sender_opinion_change.into()).unwrap();
+        }
+        if let Some(opinions) = event.opinions {
+            js_sys::Reflect::set(&obj, &"dragon1OpinionBefore".into(), &opinions.sender_before.into()).unwrap();
+            js_sys::Reflect::set(&obj, &"dragon1OpinionAfter".into(), &opinions.sender_after.into()).unwrap();
+            js_sys::Reflect::set(&obj, &"dragon2OpinionBefore".into(), &opinions.receiver_before.into()).unwrap();
+            js_sys::Reflect::set(&obj, &"dragon2OpinionAfter".into(), &opinions.receiver_after.into()).unwrap();
+        }
         obj.into()
     }
 }
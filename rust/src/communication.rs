@@ -1,4 +1,9 @@
+use crate::affinity;
+use crate::belief::Beliefs;
+use crate::bond::BondType;
 use crate::character::CharacterTraits;
+use crate::locale;
+use crate::relationship::describe_opinion;
 use crate::values::DragonValues;
 
 /// Type of communication being sent
@@ -20,6 +25,20 @@ pub enum CommunicationType {
     Shy,
 }
 
+impl CommunicationType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommunicationType::ValueAlignment => "value alignment",
+            CommunicationType::Neutral => "neutral",
+            CommunicationType::Confrontational => "confrontational",
+            CommunicationType::Friendly => "friendly",
+            CommunicationType::Playful => "playful",
+            CommunicationType::Curious => "curious",
+            CommunicationType::Shy => "shy",
+        }
+    }
+}
+
 /// Tone or intent of the communication
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CommunicationTone {
@@ -30,6 +49,18 @@ pub enum CommunicationTone {
     Challenging,
 }
 
+impl CommunicationTone {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommunicationTone::Positive => "positive",
+            CommunicationTone::Neutral => "neutral",
+            CommunicationTone::Negative => "negative",
+            CommunicationTone::Warm => "warm",
+            CommunicationTone::Challenging => "challenging",
+        }
+    }
+}
+
 /// A structured communication packet sent from one dragon to another
 #[derive(Debug, Clone)]
 pub struct Communication {
@@ -101,9 +132,12 @@ impl CommunicationResponse {
 }
 
 /// Generate a communication based on a dragon's values and traits
-/// The communication is generated purely from the sender's perspective,
+/// The communication is generated from the sender's perspective,
 /// without knowledge of the receiver's internal values or traits.
-/// The sender's opinion of the receiver can influence the communication.
+/// The sender's opinion of the receiver and what the sender has come to believe
+/// about the receiver (if anything) can influence the communication.
+/// confrontation_chance is the elemental chance of a confrontation, scaled by the sender's aggression.
+#[allow(clippy::too_many_arguments)]
 pub fn generate_communication(
     sender_values: &DragonValues,
     sender_traits: &CharacterTraits,
@@ -112,18 +146,55 @@ pub fn generate_communication(
     sender_element: &str,
     receiver_element: &str,
     existing_opinion: i32,
+    beliefs: Option<&Beliefs>,
+    confrontation_chance: f64,
 ) -> Communication {
+    // An elemental clash can provoke a confrontation, more so for aggressive dragons and never between friends
+    let chance = affinity::provocation_chance(confrontation_chance, sender_traits.aggression(), existing_opinion);
+    if chance > 0.0 {
+        use rand::Rng;
+        if rand::thread_rng().gen_bool(chance) {
+            return create_confrontational_communication(
+                sender_name,
+                receiver_name,
+                sender_element,
+                receiver_element,
+                existing_opinion,
+            );
+        }
+    }
+
     // Determine sender's primary values
     let top_sender_values = get_top_values(sender_values, 3);
     
-    // Check if sender has high values that they want to express
-    // This is based purely on the sender's values, not comparing to receiver
-    for (value_name, sender_val) in &top_sender_values {
-        if *sender_val > 70 {
-            // Sender wants to express this value - create value-based communication
-            // The tone/intensity may be influenced by existing opinion
-            return create_value_expressing_communication(
-                value_name,
+    // Check if sender has a value they want to express, preferring topics
+    // they believe the receiver resonates with
+    if let Some(value_name) = choose_value_to_express(&top_sender_values, sender_traits, existing_opinion, beliefs) {
+        // The tone/intensity may be influenced by existing opinion
+        return create_value_expressing_communication(
+            value_name,
+            sender_name,
+            receiver_name,
+            sender_element,
+            receiver_element,
+            existing_opinion,
+        );
+    }
+
+    // Adapt to what the sender believes the receiver enjoys
+    if let Some(beliefs) = beliefs {
+        if sender_traits.friendliness > 50 && sender_traits.playfulness > 40 && beliefs.trait_estimate("playfulness") > 65.0 {
+            return create_playful_communication(
+                sender_name,
+                receiver_name,
+                sender_element,
+                receiver_element,
+                existing_opinion,
+            );
+        }
+
+        if sender_traits.curiosity > 50 && beliefs.trait_estimate("curiosity") > 65.0 {
+            return create_curious_communication(
                 sender_name,
                 receiver_name,
                 sender_element,
@@ -188,6 +259,53 @@ pub fn generate_communication(
     create_neutral_communication(sender_name, receiver_name, sender_element, receiver_element, existing_opinion)
 }
 
+/// Pick which of the sender's top values to express, if any
+/// Without beliefs the sender simply expresses their strongest value above 70.
+/// With beliefs, an aggressive sender who dislikes the receiver deliberately raises
+/// a topic the receiver is known to disagree with, while everyone else picks the
+/// value the receiver seems to share and avoids topics the receiver clearly dislikes.
+pub(crate) fn choose_value_to_express<'a>(
+    top_values: &'a [(String, u32)],
+    sender_traits: &CharacterTraits,
+    existing_opinion: i32,
+    beliefs: Option<&Beliefs>,
+) -> Option<&'a str> {
+    let candidates: Vec<&str> = top_values
+        .iter()
+        .filter(|(_, value)| *value > 70)
+        .map(|(name, _)| name.as_str())
+        .collect();
+
+    let beliefs = match beliefs {
+        Some(beliefs) => beliefs,
+        None => return candidates.first().copied(),
+    };
+
+    // Provoke: talk about something the receiver is known to dislike
+    if existing_opinion < -20 && sender_traits.aggression() > 60 {
+        let provocation = top_values
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .filter(|name| beliefs.knows_value(name) && beliefs.value_estimate(name) < 40.0)
+            .min_by(|a, b| beliefs.value_estimate(a).total_cmp(&beliefs.value_estimate(b)));
+        if provocation.is_some() {
+            return provocation;
+        }
+    }
+
+    // Keep the first (strongest) value on ties
+    let best = candidates.iter().copied().fold(None, |best: Option<&str>, name| match best {
+        Some(current) if beliefs.value_estimate(current) >= beliefs.value_estimate(name) => Some(current),
+        _ => Some(name),
+    })?;
+
+    if beliefs.value_estimate(best) < 35.0 {
+        None
+    } else {
+        Some(best)
+    }
+}
+
 /// Create a communication expressing the sender's value
 /// The sender expresses what they care about, without knowing if the receiver shares it
 fn create_value_expressing_communication(
@@ -214,19 +332,13 @@ fn create_value_expressing_communication(
         base_intensity
     }.min(100);
 
-    let content = match value_name {
-        "honor" => format!("{} ({}) makes a solemn promise to {} ({})", sender_name, sender_element, receiver_name, receiver_element),
-        "community" => format!("{} ({}) proposes working together with {} ({}) for the clan's benefit", sender_name, sender_element, receiver_name, receiver_element),
-        "harmony" => format!("{} ({}) seeks peaceful resolution with {} ({})", sender_name, sender_element, receiver_name, receiver_element),
-        "wisdom" => format!("{} ({}) engages {} ({}) in deep philosophical discussion", sender_name, sender_element, receiver_name, receiver_element),
-        "protection" => format!("{} ({}) offers protection and support to {} ({})", sender_name, sender_element, receiver_name, receiver_element),
-        "freedom" => format!("{} ({}) emphasizes the importance of individual freedom to {} ({})", sender_name, sender_element, receiver_name, receiver_element),
-        "tradition" => format!("{} ({}) speaks to {} ({}) about the value of tradition", sender_name, sender_element, receiver_name, receiver_element),
-        "growth" => format!("{} ({}) discusses the importance of progress and growth with {} ({})", sender_name, sender_element, receiver_name, receiver_element),
-        "achievement" => format!("{} ({}) talks to {} ({}) about striving for excellence", sender_name, sender_element, receiver_name, receiver_element),
-        "power" => format!("{} ({}) discusses the pursuit of influence and power with {} ({})", sender_name, sender_element, receiver_name, receiver_element),
-        _ => format!("{} ({}) expresses their values to {} ({})", sender_name, sender_element, receiver_name, receiver_element),
-    };
+    let participants = Participants { sender_name, receiver_name, sender_element, receiver_element };
+    let content = participants.render(
+        &format!("communication.value_alignment.{}", value_name),
+        existing_opinion,
+        intensity,
+        Some(value_name),
+    );
 
     Communication::new(CommunicationType::ValueAlignment, content, intensity)
         .with_value(value_name.to_string())
@@ -242,15 +354,9 @@ fn create_playful_communication(
     receiver_element: &str,
     existing_opinion: i32,
 ) -> Communication {
-    use rand::Rng;
-    let mut rng = rand::thread_rng();
     let intensity = if existing_opinion > 20 { 75 } else { 70 };
-    let messages = vec![
-        format!("{} ({}) invites {} ({}) to play an energetic game", sender_name, sender_element, receiver_name, receiver_element),
-        format!("{} ({}) suggests a fun activity to {} ({})", sender_name, sender_element, receiver_name, receiver_element),
-        format!("{} ({}) playfully challenges {} ({})", sender_name, sender_element, receiver_name, receiver_element),
-    ];
-    let content = messages[rng.gen_range(0..messages.len())].clone();
+    let participants = Participants { sender_name, receiver_name, sender_element, receiver_element };
+    let content = participants.render("communication.playful", existing_opinion, intensity, None);
     Communication::new(CommunicationType::Playful, content, intensity)
         .with_trait("playfulness".to_string())
 }
@@ -262,15 +368,9 @@ fn create_friendly_communication(
     receiver_element: &str,
     existing_opinion: i32,
 ) -> Communication {
-    use rand::Rng;
-    let mut rng = rand::thread_rng();
     let intensity = if existing_opinion > 20 { 70 } else { 65 };
-    let messages = vec![
-        format!("{} ({}) greets {} ({}) warmly", sender_name, sender_element, receiver_name, receiver_element),
-        format!("{} ({}) shares a friendly conversation with {} ({})", sender_name, sender_element, receiver_name, receiver_element),
-        format!("{} ({}) offers help to {} ({})", sender_name, sender_element, receiver_name, receiver_element),
-    ];
-    let content = messages[rng.gen_range(0..messages.len())].clone();
+    let participants = Participants { sender_name, receiver_name, sender_element, receiver_element };
+    let content = participants.render("communication.friendly", existing_opinion, intensity, None);
     Communication::new(CommunicationType::Friendly, content, intensity)
         .with_trait("friendliness".to_string())
 }
@@ -282,15 +382,9 @@ fn create_curious_communication(
     receiver_element: &str,
     existing_opinion: i32,
 ) -> Communication {
-    use rand::Rng;
-    let mut rng = rand::thread_rng();
     let intensity = if existing_opinion > 20 { 65 } else { 60 };
-    let messages = vec![
-        format!("{} ({}) asks {} ({}) about something interesting", sender_name, sender_element, receiver_name, receiver_element),
-        format!("{} ({}) suggests exploring something together with {} ({})", sender_name, sender_element, receiver_name, receiver_element),
-        format!("{} ({}) shares curiosity with {} ({})", sender_name, sender_element, receiver_name, receiver_element),
-    ];
-    let content = messages[rng.gen_range(0..messages.len())].clone();
+    let participants = Participants { sender_name, receiver_name, sender_element, receiver_element };
+    let content = participants.render("communication.curious", existing_opinion, intensity, None);
     Communication::new(CommunicationType::Curious, content, intensity)
         .with_trait("curiosity".to_string())
 }
@@ -302,14 +396,9 @@ fn create_shy_communication(
     receiver_element: &str,
     existing_opinion: i32,
 ) -> Communication {
-    use rand::Rng;
-    let mut rng = rand::thread_rng();
     let intensity = if existing_opinion > 20 { 45 } else { 40 };
-    let messages = vec![
-        format!("{} ({}) tentatively approaches {} ({})", sender_name, sender_element, receiver_name, receiver_element),
-        format!("{} ({}) quietly greets {} ({})", sender_name, sender_element, receiver_name, receiver_element),
-    ];
-    let content = messages[rng.gen_range(0..messages.len())].clone();
+    let participants = Participants { sender_name, receiver_name, sender_element, receiver_element };
+    let content = participants.render("communication.shy", existing_opinion, intensity, None);
     Communication::new(CommunicationType::Shy, content, intensity)
         .with_trait("sociability".to_string())
 }
@@ -321,12 +410,15 @@ fn create_confrontational_communication(
     receiver_element: &str,
     existing_opinion: i32,
 ) -> Communication {
-    let content = if existing_opinion < -20 {
-        format!("{} ({}) confronts {} ({}) - they don't get along", sender_name, sender_element, receiver_name, receiver_element)
+    let intensity = 75;
+    let key = if existing_opinion < -20 {
+        "communication.confrontational.hostile"
     } else {
-        format!("{} ({}) challenges {} ({})", sender_name, sender_element, receiver_name, receiver_element)
+        "communication.confrontational"
     };
-    Communication::new(CommunicationType::Confrontational, content, 75)
+    let participants = Participants { sender_name, receiver_name, sender_element, receiver_element };
+    let content = participants.render(key, existing_opinion, intensity, None);
+    Communication::new(CommunicationType::Confrontational, content, intensity)
         .with_trait("aggression".to_string())
 }
 
@@ -337,19 +429,14 @@ fn create_neutral_communication(
     receiver_element: &str,
     existing_opinion: i32,
 ) -> Communication {
-    use rand::Rng;
-    let mut rng = rand::thread_rng();
     let intensity = if existing_opinion > 20 { 55 } else if existing_opinion < -20 { 45 } else { 50 };
-    let messages = vec![
-        format!("{} ({}) exchanges a brief nod with {} ({})", sender_name, sender_element, receiver_name, receiver_element),
-        format!("{} ({}) acknowledges {} ({})", sender_name, sender_element, receiver_name, receiver_element),
-        format!("{} ({}) has a polite conversation with {} ({})", sender_name, sender_element, receiver_name, receiver_element),
-    ];
-    let content = messages[rng.gen_range(0..messages.len())].clone();
+    let participants = Participants { sender_name, receiver_name, sender_element, receiver_element };
+    let content = participants.render("communication.neutral", existing_opinion, intensity, None);
     Communication::new(CommunicationType::Neutral, content, intensity)
 }
 
 /// Process a communication and generate a response
+/// Bonds the receiver holds with the sender change how strongly the communication is felt
 pub fn process_communication(
     communication: &Communication,
     receiver_values: &DragonValues,
@@ -357,6 +444,7 @@ pub fn process_communication(
     receiver_name: &str,
     sender_name: &str,
     existing_opinion: i32,
+    bonds: &[BondType],
 ) -> CommunicationResponse {
     // How the receiver interprets the communication based on their values and traits
     let interpretation = interpret_communication(communication, receiver_values, receiver_traits, receiver_name, existing_opinion);
@@ -371,6 +459,9 @@ pub fn process_communication(
         sender_name,
         existing_opinion,
     );
+    let opinion_change = bonds
+        .iter()
+        .fold(opinion_change, |change, bond| bond.weigh_opinion_change(communication.comm_type, change));
 
     CommunicationResponse::new(interpretation, response_content, opinion_change, response_tone)
 }
@@ -380,64 +471,70 @@ fn interpret_communication(
     receiver_values: &DragonValues,
     receiver_traits: &CharacterTraits,
     receiver_name: &str,
-    _existing_opinion: i32,
+    existing_opinion: i32,
 ) -> String {
+    let reply = Reply {
+        receiver_name,
+        sender_name: "",
+        existing_opinion,
+        intensity: communication.intensity,
+    };
     match communication.comm_type {
         CommunicationType::ValueAlignment => {
             if let Some(ref value_name) = communication.primary_value {
                 let receiver_val = get_value(receiver_values, value_name);
                 if receiver_val > 70 {
-                    format!("{} resonates with the shared value of {}", receiver_name, value_name)
+                    reply.render("interpretation.value_alignment.shared", Some(value_name))
                 } else if receiver_val < 30 {
-                    format!("{} doesn't share the same priority for {}", receiver_name, value_name)
+                    reply.render("interpretation.value_alignment.unshared", Some(value_name))
                 } else {
-                    format!("{} acknowledges the value of {}", receiver_name, value_name)
+                    reply.render("interpretation.value_alignment.acknowledged", Some(value_name))
                 }
             } else {
-                format!("{} receives the communication positively", receiver_name)
+                reply.render("interpretation.value_alignment", None)
             }
         }
         CommunicationType::Playful => {
             if receiver_traits.playfulness > 60 {
-                format!("{} is excited by the playful invitation", receiver_name)
+                reply.render("interpretation.playful.eager", None)
             } else if receiver_traits.playfulness < 30 {
-                format!("{} finds the playfulness a bit much", receiver_name)
+                reply.render("interpretation.playful.reluctant", None)
             } else {
-                format!("{} accepts the playful gesture", receiver_name)
+                reply.render("interpretation.playful.accepting", None)
             }
         }
         CommunicationType::Friendly => {
             if receiver_traits.friendliness > 60 {
-                format!("{} appreciates the friendly gesture", receiver_name)
+                reply.render("interpretation.friendly.appreciative", None)
             } else {
-                format!("{} responds politely", receiver_name)
+                reply.render("interpretation.friendly.polite", None)
             }
         }
         CommunicationType::Curious => {
             if receiver_traits.curiosity > 60 {
-                format!("{} shares the curiosity", receiver_name)
+                reply.render("interpretation.curious.shared", None)
             } else {
-                format!("{} acknowledges the inquiry", receiver_name)
+                reply.render("interpretation.curious.polite", None)
             }
         }
         CommunicationType::Shy => {
             if receiver_traits.sociability < 30 {
-                format!("{} appreciates the gentle approach", receiver_name)
+                reply.render("interpretation.shy.gentle", None)
             } else {
-                format!("{} responds warmly", receiver_name)
+                reply.render("interpretation.shy.warm", None)
             }
         }
         CommunicationType::Confrontational => {
             if receiver_traits.aggression() > 70 {
-                format!("{} rises to the challenge", receiver_name)
+                reply.render("interpretation.confrontational.eager", None)
             } else if receiver_traits.aggression() < 30 {
-                format!("{} is unsettled by the confrontation", receiver_name)
+                reply.render("interpretation.confrontational.unsettled", None)
             } else {
-                format!("{} responds defensively", receiver_name)
+                reply.render("interpretation.confrontational.defensive", None)
             }
         }
         CommunicationType::Neutral => {
-            format!("{} receives the communication neutrally", receiver_name)
+            reply.render("interpretation.neutral", None)
         }
     }
 }
@@ -451,6 +548,12 @@ fn generate_response(
     sender_name: &str,
     existing_opinion: i32,
 ) -> (String, i32, CommunicationTone) {
+    let reply = Reply {
+        receiver_name,
+        sender_name,
+        existing_opinion,
+        intensity: communication.intensity,
+    };
     match communication.comm_type {
         CommunicationType::ValueAlignment => {
             if let Some(ref value_name) = communication.primary_value {
@@ -459,66 +562,130 @@ fn generate_response(
                 // Check if the sender's expressed value conflicts with receiver's high values
                 let conflicts = check_value_conflicts(value_name, receiver_values);
                 if conflicts {
-                    (format!("{} disagrees with {}'s perspective on {}", receiver_name, sender_name, value_name), -5, CommunicationTone::Negative)
+                    (reply.render("response.value_alignment.conflict", Some(value_name)), -5, CommunicationTone::Negative)
                 } else if receiver_val > 70 {
                     // Shared high value - positive response
-                    (format!("{} enthusiastically agrees with {} about {}", receiver_name, sender_name, value_name), 10 + (communication.intensity as i32 / 10), CommunicationTone::Positive)
+                    (reply.render("response.value_alignment.shared", Some(value_name)), 10 + (communication.intensity as i32 / 10), CommunicationTone::Positive)
                 } else if receiver_val < 30 {
                     // Receiver doesn't prioritize this value
-                    (format!("{} politely acknowledges but doesn't share the same priority for {}", receiver_name, value_name), -3, CommunicationTone::Neutral)
+                    (reply.render("response.value_alignment.unshared", Some(value_name)), -3, CommunicationTone::Neutral)
                 } else {
                     // Moderate value alignment
-                    (format!("{} appreciates {}'s perspective on {}", receiver_name, sender_name, value_name), 5, CommunicationTone::Positive)
+                    (reply.render("response.value_alignment.moderate", Some(value_name)), 5, CommunicationTone::Positive)
                 }
             } else {
-                (format!("{} responds positively", receiver_name), 5, CommunicationTone::Positive)
+                (reply.render("response.value_alignment", None), 5, CommunicationTone::Positive)
             }
         }
         CommunicationType::Playful => {
             if receiver_traits.playfulness > 60 {
-                (format!("{} joins in the fun enthusiastically", receiver_name), 12, CommunicationTone::Positive)
+                (reply.render("response.playful.eager", None), 12, CommunicationTone::Positive)
             } else if receiver_traits.playfulness < 30 {
-                (format!("{} participates but isn't as enthusiastic", receiver_name), -2, CommunicationTone::Neutral)
+                (reply.render("response.playful.reluctant", None), -2, CommunicationTone::Neutral)
             } else {
-                (format!("{} enjoys the playful interaction", receiver_name), 8, CommunicationTone::Positive)
+                (reply.render("response.playful.accepting", None), 8, CommunicationTone::Positive)
             }
         }
         CommunicationType::Friendly => {
             if receiver_traits.friendliness > 60 {
-                (format!("{} responds warmly and reciprocates the friendliness", receiver_name), 8, CommunicationTone::Warm)
+                (reply.render("response.friendly.warm", None), 8, CommunicationTone::Warm)
             } else {
-                (format!("{} responds politely", receiver_name), 4, CommunicationTone::Neutral)
+                (reply.render("response.friendly.polite", None), 4, CommunicationTone::Neutral)
             }
         }
         CommunicationType::Curious => {
             if receiver_traits.curiosity > 60 {
-                (format!("{} shares the curiosity and engages deeply", receiver_name), 10, CommunicationTone::Positive)
+                (reply.render("response.curious.engaged", None), 10, CommunicationTone::Positive)
             } else {
-                (format!("{} responds to the inquiry", receiver_name), 5, CommunicationTone::Neutral)
+                (reply.render("response.curious.polite", None), 5, CommunicationTone::Neutral)
             }
         }
         CommunicationType::Shy => {
             if receiver_traits.sociability < 30 {
-                (format!("{} appreciates the gentle approach and opens up", receiver_name), 6, CommunicationTone::Positive)
+                (reply.render("response.shy.open", None), 6, CommunicationTone::Positive)
             } else {
-                (format!("{} responds warmly to the tentative approach", receiver_name), 3, CommunicationTone::Neutral)
+                (reply.render("response.shy.warm", None), 3, CommunicationTone::Neutral)
             }
         }
         CommunicationType::Confrontational => {
             if receiver_traits.aggression() > 70 {
-                (format!("{} rises to the challenge and confronts back", receiver_name), if existing_opinion < 0 { -20 } else { -10 }, CommunicationTone::Challenging)
+                (reply.render("response.confrontational.eager", None), if existing_opinion < 0 { -20 } else { -10 }, CommunicationTone::Challenging)
             } else if receiver_traits.aggression() < 30 {
-                (format!("{} is hurt by the confrontation and withdraws", receiver_name), -15, CommunicationTone::Negative)
+                (reply.render("response.confrontational.hurt", None), -15, CommunicationTone::Negative)
             } else {
-                (format!("{} responds defensively", receiver_name), -8, CommunicationTone::Negative)
+                (reply.render("response.confrontational.defensive", None), -8, CommunicationTone::Negative)
             }
         }
         CommunicationType::Neutral => {
-            (format!("{} responds neutrally", receiver_name), 2, CommunicationTone::Neutral)
+            (reply.render("response.neutral", None), 2, CommunicationTone::Neutral)
         }
     }
 }
 
+/// Who is talking to whom, for filling in message templates
+struct Participants<'a> {
+    sender_name: &'a str,
+    receiver_name: &'a str,
+    sender_element: &'a str,
+    receiver_element: &'a str,
+}
+
+impl Participants<'_> {
+    /// Render a random variant of the message for key
+    fn render(&self, key: &str, existing_opinion: i32, intensity: u32, value_name: Option<&str>) -> String {
+        locale::render(
+            key,
+            &[
+                ("sender", self.sender_name),
+                ("receiver", self.receiver_name),
+                ("sender_element", self.sender_element),
+                ("receiver_element", self.receiver_element),
+                ("relationship", &describe_opinion(existing_opinion)),
+                ("intensity", &intensity_adjective(intensity)),
+                ("value", &value_name.map(|v| locale::name("value", v)).unwrap_or_default()),
+            ],
+        )
+    }
+}
+
+/// Who is answering whom, for filling in interpretation and response templates
+/// Interpretations are the receiver's own thoughts, so they leave the sender empty
+struct Reply<'a> {
+    receiver_name: &'a str,
+    sender_name: &'a str,
+    existing_opinion: i32,
+    intensity: u32,
+}
+
+impl Reply<'_> {
+    /// Render a random variant of an interpretation or response
+    /// Offers the same placeholders as a sent message, apart from the elements
+    fn render(&self, key: &str, value_name: Option<&str>) -> String {
+        locale::render(
+            key,
+            &[
+                ("receiver", self.receiver_name),
+                ("sender", self.sender_name),
+                ("relationship", &describe_opinion(self.existing_opinion)),
+                ("intensity", &intensity_adjective(self.intensity)),
+                ("value", &value_name.map(|v| locale::name("value", v)).unwrap_or_default()),
+            ],
+        )
+    }
+}
+
+/// Adjective describing how intense a communication is, in the current locale
+pub fn intensity_adjective(intensity: u32) -> String {
+    let adjective = match intensity {
+        0..=40 => "hesitant",
+        41..=55 => "measured",
+        56..=70 => "earnest",
+        71..=80 => "spirited",
+        _ => "fervent",
+    };
+    locale::name("intensity", adjective)
+}
+
 // Helper functions
 fn get_top_values(values: &DragonValues, count: usize) -> Vec<(String, u32)> {
     let mut value_entries = vec![
@@ -533,7 +700,7 @@ fn get_top_values(values: &DragonValues, count: usize) -> Vec<(String, u32)> {
         ("wisdom".to_string(), values.wisdom),
         ("protection".to_string(), values.protection()),
     ];
-    value_entries.sort_by(|a, b| b.1.cmp(&a.1));
+    value_entries.sort_by_key(|entry| std::cmp::Reverse(entry.1));
     value_entries.into_iter().take(count).collect()
 }
 
@@ -553,29 +720,25 @@ fn get_value(values: &DragonValues, name: &str) -> u32 {
     }
 }
 
+/// The value on the other end of a value's axis, if it has one
+pub fn conflicting_value(value_name: &str) -> Option<&'static str> {
+    match value_name {
+        "freedom" => Some("community"),
+        "community" => Some("freedom"),
+        "tradition" => Some("growth"),
+        "growth" => Some("tradition"),
+        "power" => Some("harmony"),
+        "harmony" => Some("power"),
+        "achievement" => Some("protection"),
+        "protection" => Some("achievement"),
+        _ => None,
+    }
+}
+
 /// Check if a sender's expressed value conflicts with the receiver's high values
 fn check_value_conflicts(sender_value: &str, receiver_values: &DragonValues) -> bool {
-    // Define conflicting value pairs
-    let conflict_pairs = vec![
-        ("freedom", "community"),
-        ("community", "freedom"),
-        ("tradition", "growth"),
-        ("growth", "tradition"),
-        ("power", "harmony"),
-        ("harmony", "power"),
-        ("achievement", "protection"),
-        ("protection", "achievement"),
-    ];
-    
-    for (val1, val2) in conflict_pairs {
-        if sender_value == val1 {
-            let receiver_val2 = get_value(receiver_values, val2);
-            if receiver_val2 > 70 {
-                return true;
-            }
-        }
-    }
-    
-    false
+    conflicting_value(sender_value)
+        .map(|opposite| get_value(receiver_values, opposite) > 70)
+        .unwrap_or(false)
 }
 
@@ -1,58 +1,48 @@
-use crate::character::{generate_random_character, DragonCharacter};
+use crate::character::{generate_random_character, DragonCharacter, TraitDevelopmentConfig};
 use crate::communication::{
     generate_communication, process_communication, Communication, CommunicationResponse,
+    CommunicationTone, CommunicationType,
 };
-use crate::relationship::Relationship;
+use crate::ability::AbilityKind;
+use crate::affinity;
+use crate::element;
+use crate::epithet::{Deeds, Epithet};
+use crate::locale;
+use crate::belief::Beliefs;
+use crate::bond::{Bond, BondType};
+use crate::memory::{EpisodicMemory, Memory};
+use crate::relationship::{describe_opinion, DimensionChange, OpinionDecay, Relationship};
+use crate::values::{ValueDrift, ValueShift};
 use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[wasm_bindgen]
-pub enum DragonElement {
-    Fire,
-    Water,
-    Earth,
-    Wind,
-    Lightning,
-    Ice,
-}
-
-impl DragonElement {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            DragonElement::Fire => "Fire",
-            DragonElement::Water => "Water",
-            DragonElement::Earth => "Earth",
-            DragonElement::Wind => "Wind",
-            DragonElement::Lightning => "Lightning",
-            DragonElement::Ice => "Ice",
-        }
-    }
-
-    pub fn from_str(s: &str) -> Option<Self> {
-        match s {
-            "Fire" => Some(DragonElement::Fire),
-            "Water" => Some(DragonElement::Water),
-            "Earth" => Some(DragonElement::Earth),
-            "Wind" => Some(DragonElement::Wind),
-            "Lightning" => Some(DragonElement::Lightning),
-            "Ice" => Some(DragonElement::Ice),
-            _ => None,
-        }
-    }
-}
-
-// Helper function to convert string to DragonElement
-pub fn element_from_str(s: &str) -> Option<DragonElement> {
-    DragonElement::from_str(s)
-}
-
 #[wasm_bindgen]
 #[wasm_bindgen]
 #[derive(Debug, Clone)]
 pub struct InteractionResult {
     description: String,
     opinion_change: i32,
+    communication: Communication,
+    response_tone: CommunicationTone,
+    sender_opinion_change: i32,
+    receiver_dimension_change: DimensionChange,
+    new_bond: Option<BondType>,
+    ability: Option<AbilityKind>,
+    interpretation: String,
+    response_content: String,
+    opinions: Option<OpinionSnapshot>,
+    broke_promise: bool,
+}
+
+/// Both dragons' opinions of each other before and after an interaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpinionSnapshot {
+    /// The sender's opinion of the receiver
+    pub sender_before: i32,
+    pub sender_after: i32,
+    /// The receiver's opinion of the sender
+    pub receiver_before: i32,
+    pub receiver_after: i32,
 }
 
 #[wasm_bindgen]
@@ -62,6 +52,16 @@ impl InteractionResult {
         InteractionResult {
             description,
             opinion_change,
+            communication: Communication::new(CommunicationType::Neutral, String::new(), 0),
+            response_tone: CommunicationTone::Neutral,
+            sender_opinion_change: 0,
+            receiver_dimension_change: DimensionChange::default(),
+            new_bond: None,
+            ability: None,
+            interpretation: String::new(),
+            response_content: String::new(),
+            opinions: None,
+            broke_promise: false,
         }
     }
 
@@ -76,30 +76,147 @@ impl InteractionResult {
     }
 }
 
+impl InteractionResult {
+    /// Attach the details of the exchange that produced this result
+    pub(crate) fn with_exchange(
+        mut self,
+        communication: Communication,
+        response_tone: CommunicationTone,
+        sender_opinion_change: i32,
+    ) -> Self {
+        self.communication = communication;
+        self.response_tone = response_tone;
+        self.sender_opinion_change = sender_opinion_change;
+        self
+    }
+
+    /// The communication the sender sent
+    pub(crate) fn communication(&self) -> &Communication {
+        &self.communication
+    }
+
+    pub(crate) fn comm_type(&self) -> CommunicationType {
+        self.communication.comm_type
+    }
+
+    pub(crate) fn response_tone(&self) -> CommunicationTone {
+        self.response_tone
+    }
+
+    /// How the sender's opinion of the receiver changed
+    pub(crate) fn sender_opinion_change(&self) -> i32 {
+        self.sender_opinion_change
+    }
+
+    /// Attach how the receiver's respect, trust and fear of the sender changed
+    pub(crate) fn with_receiver_dimension_change(mut self, change: DimensionChange) -> Self {
+        self.receiver_dimension_change = change;
+        self
+    }
+
+    /// How the receiver's respect, trust and fear of the sender changed
+    pub(crate) fn receiver_dimension_change(&self) -> DimensionChange {
+        self.receiver_dimension_change
+    }
+
+    /// Record a bond the interaction formed, from the sender's perspective
+    pub(crate) fn with_new_bond(mut self, bond: Option<BondType>) -> Self {
+        self.new_bond = bond;
+        self
+    }
+
+    /// Bond formed by this interaction, from the sender's perspective
+    pub(crate) fn new_bond(&self) -> Option<BondType> {
+        self.new_bond
+    }
+
+    /// Mark this result as the use of an elemental ability rather than a communication
+    pub(crate) fn with_ability(mut self, ability: AbilityKind) -> Self {
+        self.ability = Some(ability);
+        self
+    }
+
+    /// Elemental ability used, if this result was not a communication
+    pub(crate) fn ability(&self) -> Option<AbilityKind> {
+        self.ability
+    }
+
+    /// Attach how the receiver read the communication and what they said back
+    pub(crate) fn with_reply(mut self, interpretation: String, response_content: String) -> Self {
+        self.interpretation = interpretation;
+        self.response_content = response_content;
+        self
+    }
+
+    /// How the receiver read the communication
+    pub(crate) fn interpretation(&self) -> &str {
+        &self.interpretation
+    }
+
+    /// What the receiver said back
+    pub(crate) fn response_content(&self) -> &str {
+        &self.response_content
+    }
+
+    /// Record both dragons' opinions of each other before and after the interaction
+    pub(crate) fn with_opinions(mut self, opinions: OpinionSnapshot) -> Self {
+        self.opinions = Some(opinions);
+        self
+    }
+
+    /// Both dragons' opinions before and after, if the interaction was between two dragons
+    pub(crate) fn opinions(&self) -> Option<OpinionSnapshot> {
+        self.opinions
+    }
+
+    /// Shift how much the receiver's opinion changed, e.g. by its faction loyalties
+    pub(crate) fn with_opinion_modifier(mut self, modifier: i32) -> Self {
+        self.opinion_change += modifier;
+        self
+    }
+
+    /// Record whether the sender broke an oath of honor to the receiver
+    pub(crate) fn with_broken_promise(mut self, broke_promise: bool) -> Self {
+        self.broke_promise = broke_promise;
+        self
+    }
+
+    /// Whether the sender broke an oath of honor to the receiver
+    pub(crate) fn broke_promise(&self) -> bool {
+        self.broke_promise
+    }
+}
+
 #[wasm_bindgen]
 #[derive(Clone)]
 pub struct Dragon {
     name: String,
-    element: DragonElement,
+    /// Name of the dragon's element in the element registry
+    element: String,
+    /// Second element of a hybrid dragon, which shapes it less than its primary element
+    secondary_element: Option<String>,
     age: u32,
     character: DragonCharacter,
     relationships: HashMap<String, Relationship>,
+    memories: EpisodicMemory,
+    bonds: Vec<Bond>,
+    parents: Option<(String, String)>,
+    beliefs: HashMap<String, Beliefs>,
+    value_drift: ValueDrift,
+    ability_ready_day: u32,
+    deeds: Deeds,
 }
 
 #[wasm_bindgen]
 impl Dragon {
     #[wasm_bindgen(constructor)]
     pub fn new(name: String, element_str: String, age: u32) -> Self {
-        let element = DragonElement::from_str(&element_str).unwrap_or(DragonElement::Fire);
-        let character = generate_random_character(Some(element));
+        Dragon::new_hybrid(name, element_str, None, age)
+    }
 
-        Dragon {
-            name,
-            element,
-            age,
-            character,
-            relationships: HashMap::new(),
-        }
+    #[wasm_bindgen(getter)]
+    pub fn secondary_element(&self) -> Option<String> {
+        self.secondary_element.clone()
     }
 
     #[wasm_bindgen(getter)]
@@ -109,7 +226,7 @@ impl Dragon {
 
     #[wasm_bindgen(getter)]
     pub fn element(&self) -> String {
-        self.element.as_str().to_string()
+        self.element.clone()
     }
 
     #[wasm_bindgen(getter)]
@@ -120,8 +237,7 @@ impl Dragon {
     /// Get existing relationship, or None if no relationship exists yet
     /// Relationships are only created through actual interactions
     fn get_relationship(&self, other: &Dragon) -> Option<&Relationship> {
-        let other_name = other.name.clone();
-        self.relationships.get(&other_name)
+        self.relationships.get(&other.name)
     }
 
     /// Get mutable relationship, creating it only if it doesn't exist
@@ -130,7 +246,7 @@ impl Dragon {
         let other_name = other.name.clone();
         self.relationships
             .entry(other_name.clone())
-            .or_insert_with(|| Relationship::new())
+            .or_insert_with(Relationship::new)
     }
 
     /// Get opinion of another dragon
@@ -151,20 +267,25 @@ impl Dragon {
     }
 
     /// Generate a communication to send to another dragon
-    /// The communication is based on this dragon's values and traits
+    /// The communication is based on this dragon's values and traits,
+    /// and on what it believes about the other dragon
     /// Internal method - not exposed to WASM
     fn communicate_with(&self, other: &Dragon) -> Communication {
         let existing_opinion = self.get_opinion_of(other);
         let sender_name = self.name.clone();
         let receiver_name = other.name.clone();
+        // Elemental clashes make a sender more likely to pick a fight
+        let affinity = affinity::get_affinity(&other.element, &self.element);
         generate_communication(
             self.character.values(),
             self.character.traits(),
             &sender_name,
             &receiver_name,
-            self.element.as_str(),
-            other.element.as_str(),
+            &self.element_label(),
+            &other.element_label(),
             existing_opinion,
+            self.beliefs.get(&other.name),
+            affinity::confrontation_chance(affinity),
         )
     }
 
@@ -178,20 +299,42 @@ impl Dragon {
         let other_name = other.name.clone();
         let sender_name = self.name.clone();
         let existing_opinion = self.get_opinion_of(other);
-        let response = process_communication(
+        let receiver_bonds = other.bond_types_with(self);
+        let mut response = process_communication(
             &communication,
             other.character.values(),
             other.character.traits(),
             &other_name,
             &sender_name,
             existing_opinion,
+            &receiver_bonds,
         );
 
+        // The receiver's element colours how they feel about the sender
+        let affinity = affinity::get_affinity(&self.element, &other.element);
+        response.opinion_change += affinity::opinion_modifier(affinity);
+
         // Update this dragon's opinion based on the response
         // The sender's opinion changes based on how their communication was received
         let sender_opinion_change = calculate_sender_opinion_change(&communication, &response);
+        let sender_dimension_change =
+            DimensionChange::for_sender(communication.comm_type, response.response_tone);
         let relationship = self.get_or_create_relationship(other);
         relationship.update_opinion(sender_opinion_change);
+        relationship.update_dimensions(sender_dimension_change);
+
+        // The sender learns about the receiver from how they responded
+        self.beliefs
+            .entry(other_name.clone())
+            .or_default()
+            .observe_response(&communication, response.response_tone);
+
+        // The receiver's view of the sender shifts along each dimension differently
+        let receiver_dimension_change = DimensionChange::for_receiver(
+            communication.comm_type,
+            response.response_tone,
+            communication.intensity,
+        );
 
         // Create combined description for display
         let full_description = format!(
@@ -205,17 +348,25 @@ impl Dragon {
             response.interpretation
         );
 
-        InteractionResult::new(full_description, response.opinion_change)
+        InteractionResult::new(full_description, response.opinion_change).with_exchange(
+            communication,
+            response.response_tone,
+            sender_opinion_change,
+        )
+        .with_receiver_dimension_change(receiver_dimension_change)
+        .with_reply(response.interpretation, response.response_content)
     }
 
     pub fn get_info(&self) -> String {
         let style = self.character.get_interaction_style();
-        format!(
-            "{} - {} Dragon, Age: {}, Style: {}",
-            self.name,
-            self.element.as_str(),
-            self.age,
-            style
+        locale::render(
+            "dragon.info",
+            &[
+                ("name", &self.name),
+                ("element", &self.element_label()),
+                ("age", &self.age.to_string()),
+                ("style", &style),
+            ],
         )
     }
 
@@ -224,62 +375,81 @@ impl Dragon {
         let values = self.character.values();
         let style = self.character.get_interaction_style();
 
-        let mut trait_entries: Vec<(String, u32)> = vec![
-            ("Friendliness".to_string(), traits.friendliness),
-            ("Aggression".to_string(), traits.aggression()),
-            ("Sociability".to_string(), traits.sociability),
-            ("Curiosity".to_string(), traits.curiosity),
-            ("Playfulness".to_string(), traits.playfulness),
-            ("Dominance".to_string(), traits.dominance),
-            ("Patience".to_string(), traits.patience()),
+        let mut trait_entries: Vec<(&str, u32)> = vec![
+            ("friendliness", traits.friendliness),
+            ("aggression", traits.aggression()),
+            ("sociability", traits.sociability),
+            ("curiosity", traits.curiosity),
+            ("playfulness", traits.playfulness),
+            ("dominance", traits.dominance),
+            ("patience", traits.patience()),
         ];
-        trait_entries.sort_by(|a, b| b.1.cmp(&a.1));
-
-        let mut value_entries: Vec<(String, u32)> = vec![
-            ("Honor".to_string(), values.honor),
-            ("Freedom".to_string(), values.freedom()),
-            ("Tradition".to_string(), values.tradition()),
-            ("Growth".to_string(), values.growth()),
-            ("Community".to_string(), values.community()),
-            ("Achievement".to_string(), values.achievement()),
-            ("Harmony".to_string(), values.harmony()),
-            ("Power".to_string(), values.power()),
-            ("Wisdom".to_string(), values.wisdom),
-            ("Protection".to_string(), values.protection()),
+        trait_entries.sort_by_key(|entry| std::cmp::Reverse(entry.1));
+
+        let mut value_entries: Vec<(&str, u32)> = vec![
+            ("honor", values.honor),
+            ("freedom", values.freedom()),
+            ("tradition", values.tradition()),
+            ("growth", values.growth()),
+            ("community", values.community()),
+            ("achievement", values.achievement()),
+            ("harmony", values.harmony()),
+            ("power", values.power()),
+            ("wisdom", values.wisdom),
+            ("protection", values.protection()),
         ];
-        value_entries.sort_by(|a, b| b.1.cmp(&a.1));
-
-        let traits_str: String = trait_entries
-            .iter()
-            .map(|(key, value)| format!("  {}: {}/100", key, value))
-            .collect::<Vec<_>>()
-            .join("\n");
-
-        let values_str: String = value_entries
-            .iter()
-            .map(|(key, value)| format!("  {}: {}/100", key, value))
-            .collect::<Vec<_>>()
-            .join("\n");
-
-        format!(
-            "{}'s Details:\n  Element: {}\n  Age: {}\n\n  Character:\n  Style: {}\n\n  Traits:\n{}\n\n  Values:\n{}",
-            self.name, self.element.as_str(), self.age, style, traits_str, values_str
+        value_entries.sort_by_key(|entry| std::cmp::Reverse(entry.1));
+
+        let scores = |kind: &str, entries: &[(&str, u32)]| -> String {
+            entries
+                .iter()
+                .map(|(key, value)| {
+                    locale::render(
+                        "dragon.character_score",
+                        &[
+                            ("name", &capitalize(&locale::name(kind, key))),
+                            ("score", &value.to_string()),
+                        ],
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        locale::render(
+            "dragon.character_info",
+            &[
+                ("name", &self.name),
+                ("element", &self.element_label()),
+                ("age", &self.age.to_string()),
+                ("style", &style),
+                ("traits", &scores("trait", &trait_entries)),
+                ("values", &scores("value", &value_entries)),
+            ],
         )
     }
 
     pub fn get_relationship_info(&self, other: &Dragon) -> String {
         if let Some(relationship) = self.get_relationship(other) {
-            let status = relationship.get_relationship_status();
-            let description = relationship.get_relationship_description();
-            format!(
-                "{} {} ({}/100, {} interactions)",
-                status,
-                description,
-                relationship.opinion(),
-                relationship.interaction_count()
+            locale::render_plural(
+                "relationship.info",
+                relationship.interaction_count(),
+                &[
+                    ("status", &relationship.get_relationship_status()),
+                    ("description", &relationship.get_relationship_description()),
+                    ("opinion", &relationship.opinion().to_string()),
+                ],
             )
         } else {
-            "😐 neutral (0/100, 0 interactions)".to_string()
+            locale::render_plural(
+                "relationship.info",
+                0,
+                &[
+                    ("status", "😐"),
+                    ("description", &describe_opinion(0)),
+                    ("opinion", "0"),
+                ],
+            )
         }
     }
 
@@ -289,6 +459,287 @@ impl Dragon {
     }
 }
 
+// Crate-internal methods that are not exposed to WASM
+impl Dragon {
+    /// Create a dragon with a primary and optional secondary element
+    /// Unknown primary elements fall back to Fire; an unknown or repeated secondary element is dropped
+    pub(crate) fn new_hybrid(name: String, element_str: String, secondary_element: Option<String>, age: u32) -> Self {
+        let element = if element::is_known(&element_str) {
+            element_str
+        } else {
+            element::DEFAULT_ELEMENT.to_string()
+        };
+        let secondary_element =
+            secondary_element.filter(|secondary| *secondary != element && element::is_known(secondary));
+        let character = generate_random_character(Some(&element), secondary_element.as_deref());
+
+        Dragon {
+            name,
+            element,
+            secondary_element,
+            age,
+            character,
+            relationships: HashMap::new(),
+            memories: EpisodicMemory::default(),
+            bonds: Vec::new(),
+            parents: None,
+            beliefs: HashMap::new(),
+            value_drift: ValueDrift::default(),
+            ability_ready_day: 0,
+            deeds: Deeds::default(),
+        }
+    }
+
+    /// Label of get_interaction_style that doesn't depend on the locale, e.g. "playful"
+    pub(crate) fn interaction_style(&self) -> &'static str {
+        self.character.interaction_style()
+    }
+
+    /// Element for display in the current locale, e.g. "Fire" or "Fire/Ice" for a hybrid
+    pub(crate) fn element_label(&self) -> String {
+        let element = locale::name("element", &self.element);
+        match self.secondary_element {
+            Some(ref secondary) => format!("{}/{}", element, locale::name("element", secondary)),
+            None => element,
+        }
+    }
+
+    /// Apply the receiver's side of an interaction started by another dragon
+    pub(crate) fn receive_interaction(&mut self, sender: &Dragon, result: &InteractionResult) {
+        let relationship = self.get_or_create_relationship(sender);
+        relationship.update_opinion(result.opinion_change());
+        relationship.update_dimensions(result.receiver_dimension_change());
+
+        // The receiver learns about the sender from what they chose to say
+        self.beliefs
+            .entry(sender.name.clone())
+            .or_default()
+            .observe_communication(result.communication());
+    }
+
+    /// Let a value expressed by another dragon shift this dragon's own values
+    /// Younger dragons are more easily swayed than elders
+    pub(crate) fn absorb_influence(&mut self, influencer: &str, result: &InteractionResult, day: u32) {
+        let communication = result.communication();
+        let value_name = match communication.primary_value {
+            Some(ref value_name) => value_name.clone(),
+            None => return,
+        };
+        self.value_drift.influence(
+            &mut self.character.values,
+            &value_name,
+            result.opinion_change(),
+            communication.intensity,
+            self.age,
+            influencer,
+            day,
+        );
+    }
+
+    /// Let this dragon's traits develop from how a communication it sent was received
+    pub(crate) fn develop_from_interaction(
+        &mut self,
+        receiver_dominance: u32,
+        result: &InteractionResult,
+        config: &TraitDevelopmentConfig,
+    ) {
+        match result.response_tone() {
+            CommunicationTone::Negative | CommunicationTone::Challenging => {
+                self.character.record_rejection(config);
+            }
+            CommunicationTone::Positive | CommunicationTone::Warm => {
+                if self.character.traits().dominance > receiver_dominance {
+                    self.character.record_leadership(config);
+                }
+            }
+            CommunicationTone::Neutral => {}
+        }
+    }
+
+    /// Grow one year older
+    pub(crate) fn grow_older(&mut self, config: &TraitDevelopmentConfig) {
+        self.age += 1;
+        self.character.age_one_year(config);
+    }
+
+    /// Score of one of this dragon's values, 0 if the name is unknown
+    pub(crate) fn value(&self, name: &str) -> u32 {
+        self.character.values().get(name).unwrap_or(0)
+    }
+
+    pub(crate) fn dominance(&self) -> u32 {
+        self.character.traits().dominance
+    }
+
+    /// This dragon's signature elemental ability, if its element has one
+    pub(crate) fn ability(&self) -> Option<AbilityKind> {
+        AbilityKind::for_element(&self.element)
+    }
+
+    /// Days until the ability can be used again, 0 if it is ready
+    pub(crate) fn ability_cooldown(&self, today: u32) -> u32 {
+        self.ability_ready_day.saturating_sub(today)
+    }
+
+    /// Start the ability's cooldown after using it
+    pub(crate) fn start_ability_cooldown(&mut self, today: u32) {
+        if let Some(ability) = self.ability() {
+            self.ability_ready_day = today + ability.cooldown_days();
+        }
+    }
+
+    /// Move negative opinions of other dragons toward neutral
+    pub(crate) fn soften_grudges(&mut self, fraction: f64) {
+        for relationship in self.relationships.values_mut() {
+            relationship.soften_grudge(fraction);
+        }
+    }
+
+    /// Recorded shifts in this dragon's values, oldest first
+    pub(crate) fn value_history(&self) -> impl Iterator<Item = &ValueShift> {
+        self.value_drift.history()
+    }
+
+    /// What this dragon believes about another's values and traits, if it has formed any beliefs
+    pub(crate) fn beliefs_about(&self, other: &Dragon) -> Option<&Beliefs> {
+        self.beliefs.get(&other.name)
+    }
+
+    /// This dragon's relationship with another, if they have interacted
+    pub(crate) fn relationship_with(&self, other: &Dragon) -> Option<&Relationship> {
+        self.get_relationship(other)
+    }
+
+    /// Mutable relationship with the named dragon, creating it if needed
+    pub(crate) fn relationship_with_mut(&mut self, other_name: &str) -> &mut Relationship {
+        self.relationships
+            .entry(other_name.to_string())
+            .or_insert_with(Relationship::new)
+    }
+
+    /// Mutable relationship with the named dragon, if they have met
+    pub(crate) fn existing_relationship_mut(&mut self, other_name: &str) -> Option<&mut Relationship> {
+        self.relationships.get_mut(other_name)
+    }
+
+    /// Let opinions of dragons not met since their last contact drift toward neutral
+    /// Close bonds slow the drift, and impatient dragons hold on to grudges longer
+    pub(crate) fn decay_opinions(&mut self, today: u32, decay: &OpinionDecay) {
+        let patience = self.character.traits().patience();
+        for (other_name, relationship) in self.relationships.iter_mut() {
+            let bonded = self
+                .bonds
+                .iter()
+                .any(|bond| &bond.other_name == other_name && bond.bond_type.slows_decay());
+            let half_life = decay.half_life(relationship.opinion(), bonded, patience);
+            relationship.decay(today, half_life);
+        }
+    }
+
+    /// All bonds this dragon holds, in the order they formed
+    pub(crate) fn bonds(&self) -> &[Bond] {
+        &self.bonds
+    }
+
+    /// Kinds of bond this dragon holds with another
+    pub(crate) fn bond_types_with(&self, other: &Dragon) -> Vec<BondType> {
+        self.bonds
+            .iter()
+            .filter(|bond| bond.other_name == other.name)
+            .map(|bond| bond.bond_type)
+            .collect()
+    }
+
+    pub(crate) fn has_mate(&self) -> bool {
+        self.bonds.iter().any(|bond| bond.bond_type == BondType::Mate)
+    }
+
+    /// Form a bond with another dragon, ignoring duplicates
+    pub(crate) fn form_bond(&mut self, other_name: &str, bond_type: BondType, day: u32) {
+        let exists = self
+            .bonds
+            .iter()
+            .any(|bond| bond.other_name == other_name && bond.bond_type == bond_type);
+        if !exists {
+            self.bonds.push(Bond {
+                other_name: other_name.to_string(),
+                bond_type,
+                formed_day: day,
+            });
+        }
+    }
+
+    /// Names of this dragon's parents, if it hatched in the clan
+    pub(crate) fn parents(&self) -> Option<&(String, String)> {
+        self.parents.as_ref()
+    }
+
+    pub(crate) fn set_parents(&mut self, parent1: String, parent2: String) {
+        self.parents = Some((parent1, parent2));
+    }
+
+    /// Whether this dragon is one of the other dragon's parents
+    pub(crate) fn is_parent_of(&self, other: &Dragon) -> bool {
+        other
+            .parents
+            .as_ref()
+            .is_some_and(|(parent1, parent2)| *parent1 == self.name || *parent2 == self.name)
+    }
+
+    /// Note the deeds in a communication this dragon started
+    /// Returns true if it broke an oath of honor to the receiver
+    pub(crate) fn record_deeds(&mut self, receiver: &str, result: &InteractionResult, day: u32) -> bool {
+        self.deeds.record_communication(receiver, result, day)
+    }
+
+    pub(crate) fn record_ability_use(&mut self, kind: AbilityKind) {
+        self.deeds.record_ability(kind);
+    }
+
+    /// Count oaths that have held long enough as kept
+    pub(crate) fn settle_oaths(&mut self, today: u32) {
+        self.deeds.settle_oaths(today);
+    }
+
+    /// Forget oaths sworn to a dragon that has left the clan
+    pub(crate) fn release_oaths_to(&mut self, name: &str) {
+        self.deeds.release_oaths_to(name);
+    }
+
+    /// Title earned from this dragon's deeds, if any
+    pub(crate) fn epithet(&self) -> Option<Epithet> {
+        self.deeds.epithet()
+    }
+
+    /// Name with any earned epithet, e.g. "Ignis the Oathkeeper"
+    pub(crate) fn display_name(&self) -> String {
+        match self.epithet() {
+            Some(epithet) => locale::render(
+                "dragon.display_name",
+                &[("name", &self.name), ("epithet", &epithet.title())],
+            ),
+            None => self.name.clone(),
+        }
+    }
+
+    /// Remember an interaction if it was significant enough
+    pub(crate) fn remember(&mut self, memory: Memory) {
+        if Memory::is_significant(memory.comm_type, memory.tone, memory.opinion_change) {
+            self.memories.record(memory);
+        }
+    }
+
+    /// Notable interactions this dragon remembers, oldest first
+    pub(crate) fn memories(&self) -> &[Memory] {
+        self.memories.memories()
+    }
+
+    /// Notable interactions this dragon remembers with another dragon, oldest first
+    pub(crate) fn memories_of(&self, other: &Dragon) -> Vec<&Memory> {
+        self.memories.about(&other.name)
+    }
+}
+
 // Test-only implementation block for methods that don't need WASM bindings
 #[cfg(test)]
 impl Dragon {
@@ -296,20 +747,37 @@ impl Dragon {
     /// This allows for predictable, statically generated dragons in tests
     pub fn with_character(
         name: String,
-        element: DragonElement,
+        element: &str,
         age: u32,
         character: DragonCharacter,
     ) -> Self {
         Dragon {
             name,
-            element,
+            element: element.to_string(),
+            secondary_element: None,
             age,
             character,
             relationships: std::collections::HashMap::new(),
+            memories: EpisodicMemory::default(),
+            bonds: Vec::new(),
+            parents: None,
+            beliefs: std::collections::HashMap::new(),
+            value_drift: ValueDrift::default(),
+            ability_ready_day: 0,
+            deeds: Deeds::default(),
         }
     }
 }
 
+/// Upper-case the first letter, e.g. "honor" to "Honor"
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
 /// Calculate how the sender's opinion changes based on the response to their communication
 fn calculate_sender_opinion_change(
     _communication: &Communication,
@@ -344,7 +812,7 @@ mod tests {
     /// This is used for testing to create predictable, statically generated dragons
     fn create_static_dragon(
         name: String,
-        element: DragonElement,
+        element: &str,
         age: u32,
         traits: CharacterTraits,
         values: DragonValues,
@@ -358,7 +826,7 @@ mod tests {
         // Create first dragon: Friendly and values honor highly
         let mut aurora = create_static_dragon(
             "Aurora".to_string(),
-            DragonElement::Water,
+            "Water",
             10,
             CharacterTraits {
                 friendliness: 80,
@@ -381,7 +849,7 @@ mod tests {
         // Create second dragon: Also friendly, values honor highly (should resonate)
         let luna = create_static_dragon(
             "Luna".to_string(),
-            DragonElement::Water,
+            "Water",
             12,
             CharacterTraits {
                 friendliness: 75,
@@ -433,5 +901,11 @@ mod tests {
             description.contains("→") || description.contains(":"),
             "Description should show communication flow"
         );
+
+        // The structured record carries the same exchange the description shows
+        assert!(!result.interpretation().is_empty());
+        assert!(description.contains(result.communication().content.as_str()));
+        assert!(description.contains(result.response_content()));
+        assert!(description.contains(result.interpretation()));
     }
 }
@@ -0,0 +1,261 @@
+use crate::ability::AbilityKind;
+use crate::character::TRAIT_NAMES;
+use crate::values::VALUE_NAMES;
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Built-in element definitions, embedded at compile time
+const DEFAULT_ELEMENTS: &str = include_str!("../data/elements.json");
+
+/// Element a dragon gets when given one that isn't registered
+pub const DEFAULT_ELEMENT: &str = "Fire";
+
+/// How strongly a hybrid's secondary element shapes it, relative to its primary element
+pub const SECONDARY_ELEMENT_WEIGHT: f64 = 0.5;
+
+/// Everything that makes an element distinct, as loaded from data
+#[derive(Debug, Clone, Deserialize)]
+pub struct ElementDefinition {
+    pub name: String,
+    /// Name of the element's signature ability, if it has one
+    #[serde(default)]
+    pub ability: Option<String>,
+    /// Trait adjustments applied to newly generated dragons, by trait name
+    #[serde(default)]
+    pub trait_nudges: HashMap<String, i32>,
+    /// Value adjustments applied to newly generated dragons, by value name
+    #[serde(default)]
+    pub value_nudges: HashMap<String, i32>,
+    #[serde(default)]
+    pub name_prefixes: Vec<String>,
+    #[serde(default)]
+    pub name_suffixes: Vec<String>,
+    /// Seed names the Markov name generator learns this element's flavour from
+    #[serde(default)]
+    pub name_corpus: Vec<String>,
+    /// Adjectives for clan names when this element dominates the clan, e.g. "Frozen"
+    #[serde(default)]
+    pub clan_adjectives: Vec<String>,
+    /// How receivers of each listed element react to communications from this element (-100 to 100)
+    #[serde(default)]
+    pub affinities: HashMap<String, i32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ElementFile {
+    elements: Vec<ElementDefinition>,
+}
+
+/// All elements dragons can have, in definition order
+#[derive(Debug, Clone)]
+pub struct ElementRegistry {
+    elements: Vec<ElementDefinition>,
+}
+
+impl ElementRegistry {
+    /// Parse element definitions from JSON of the form {"elements": [...]}
+    /// Fails on unknown trait or value names in the nudges and on unknown abilities
+    pub fn from_json(json: &str) -> Result<Vec<ElementDefinition>, String> {
+        let file: ElementFile = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        for definition in &file.elements {
+            let name = &definition.name;
+            if name.trim().is_empty() {
+                return Err("element name must not be empty".to_string());
+            }
+            if let Some(nudged) = definition.trait_nudges.keys().find(|t| !TRAIT_NAMES.contains(&t.as_str())) {
+                return Err(format!("element {} nudges unknown trait {}", name, nudged));
+            }
+            if let Some(nudged) = definition.value_nudges.keys().find(|v| !VALUE_NAMES.contains(&v.as_str())) {
+                return Err(format!("element {} nudges unknown value {}", name, nudged));
+            }
+            if let Some(ability) = definition.ability.as_deref().filter(|a| AbilityKind::from_str(a).is_none()) {
+                return Err(format!("element {} has unknown ability {}", name, ability));
+            }
+        }
+        Ok(file.elements)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ElementDefinition> {
+        self.elements.iter().find(|e| e.name == name)
+    }
+
+    pub fn elements(&self) -> &[ElementDefinition] {
+        &self.elements
+    }
+
+    /// Add new elements, replacing any existing element with the same name
+    pub fn register(&mut self, definitions: Vec<ElementDefinition>) {
+        for definition in definitions {
+            match self.elements.iter_mut().find(|e| e.name == definition.name) {
+                Some(existing) => *existing = definition,
+                None => self.elements.push(definition),
+            }
+        }
+    }
+}
+
+impl Default for ElementRegistry {
+    fn default() -> Self {
+        ElementRegistry {
+            elements: ElementRegistry::from_json(DEFAULT_ELEMENTS)
+                .expect("built-in element definitions are valid"),
+        }
+    }
+}
+
+// Global element registry
+// For WASM (single-threaded), we use a simple thread_local with RefCell
+thread_local! {
+    static ELEMENT_REGISTRY: RefCell<ElementRegistry> = RefCell::new(ElementRegistry::default());
+}
+
+/// Look up an element definition and read from it
+pub fn with_element<R>(name: &str, f: impl FnOnce(&ElementDefinition) -> R) -> Option<R> {
+    ELEMENT_REGISTRY.with(|registry| registry.borrow().get(name).map(f))
+}
+
+pub fn is_known(name: &str) -> bool {
+    with_element(name, |_| ()).is_some()
+}
+
+/// Names of all registered elements, in definition order
+pub fn element_names() -> Vec<String> {
+    ELEMENT_REGISTRY.with(|registry| {
+        registry
+            .borrow()
+            .elements()
+            .iter()
+            .map(|e| e.name.clone())
+            .collect()
+    })
+}
+
+/// Pick a registered element at random
+pub fn random_element() -> String {
+    use rand::seq::SliceRandom;
+    element_names()
+        .choose(&mut rand::thread_rng())
+        .cloned()
+        .unwrap_or_else(|| DEFAULT_ELEMENT.to_string())
+}
+
+/// Combine the nudges of a primary and optional secondary element
+/// The secondary element's nudges count at SECONDARY_ELEMENT_WEIGHT
+fn blend_nudges(
+    primary: &str,
+    secondary: Option<&str>,
+    select: fn(&ElementDefinition) -> &HashMap<String, i32>,
+) -> HashMap<String, i32> {
+    let mut nudges = with_element(primary, |e| select(e).clone()).unwrap_or_default();
+    if let Some(secondary) = secondary {
+        let secondary_nudges = with_element(secondary, |e| select(e).clone()).unwrap_or_default();
+        for (name, delta) in secondary_nudges {
+            let weighted = (delta as f64 * SECONDARY_ELEMENT_WEIGHT).round() as i32;
+            *nudges.entry(name).or_insert(0) += weighted;
+        }
+    }
+    nudges
+}
+
+/// Trait nudges for a dragon of the given element(s)
+pub fn blended_trait_nudges(primary: &str, secondary: Option<&str>) -> HashMap<String, i32> {
+    blend_nudges(primary, secondary, |e| &e.trait_nudges)
+}
+
+/// Value nudges for a dragon of the given element(s)
+pub fn blended_value_nudges(primary: &str, secondary: Option<&str>) -> HashMap<String, i32> {
+    blend_nudges(primary, secondary, |e| &e.value_nudges)
+}
+
+/// Name prefixes and suffixes of an element, if it is registered
+pub fn name_syllables(name: &str) -> Option<(Vec<String>, Vec<String>)> {
+    with_element(name, |e| (e.name_prefixes.clone(), e.name_suffixes.clone()))
+}
+
+/// Seed names for the given elements, combined, for training the Markov name generator
+pub fn name_corpus(names: &[&str]) -> Vec<String> {
+    names
+        .iter()
+        .filter_map(|name| with_element(name, |e| e.name_corpus.clone()))
+        .flatten()
+        .collect()
+}
+
+/// Clan name adjectives of an element, empty if it is unknown or has none
+pub fn clan_adjectives(name: &str) -> Vec<String> {
+    with_element(name, |e| e.clan_adjectives.clone()).unwrap_or_default()
+}
+
+/// Every registered element's affinities as (sender, receiver, affinity)
+pub fn affinity_entries() -> Vec<(String, String, i32)> {
+    ELEMENT_REGISTRY.with(|registry| {
+        registry
+            .borrow()
+            .elements()
+            .iter()
+            .flat_map(|e| {
+                e.affinities
+                    .iter()
+                    .map(move |(receiver, affinity)| (e.name.clone(), receiver.clone(), *affinity))
+            })
+            .collect()
+    })
+}
+
+/// Load element definitions from JSON, adding to or replacing the registered elements
+/// Returns the names of the elements loaded
+pub fn load_elements(json: &str) -> Result<Vec<String>, String> {
+    let definitions = ElementRegistry::from_json(json)?;
+    let names = definitions.iter().map(|d| d.name.clone()).collect();
+    ELEMENT_REGISTRY.with(|registry| registry.borrow_mut().register(definitions));
+    Ok(names)
+}
+
+/// Restore the built-in elements
+pub fn reset_elements() {
+    ELEMENT_REGISTRY.with(|registry| *registry.borrow_mut() = ElementRegistry::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_element_extends_registry() {
+        let mut registry = ElementRegistry::default();
+        assert_eq!(registry.elements().len(), 6);
+
+        let definitions = ElementRegistry::from_json(
+            r#"{"elements": [{"name": "Shadow", "trait_nudges": {"sociability": -15}, "affinities": {"Fire": -30}}]}"#,
+        )
+        .unwrap();
+        registry.register(definitions);
+
+        let shadow = registry.get("Shadow").unwrap();
+        assert_eq!(shadow.trait_nudges.get("sociability"), Some(&-15));
+        assert!(shadow.ability.is_none());
+        assert!(shadow.name_prefixes.is_empty());
+        assert_eq!(registry.elements().len(), 7);
+
+        for invalid in [
+            r#"{"elements": [{"name": ""}]}"#,
+            r#"{"elements": [{"name": "Shadow", "trait_nudges": {"sneakiness": 10}}]}"#,
+            r#"{"elements": [{"name": "Shadow", "value_nudges": {"secrecy": 10}}]}"#,
+            r#"{"elements": [{"name": "Shadow", "ability": "vanishing"}]}"#,
+        ] {
+            assert!(ElementRegistry::from_json(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_hybrid_blends_secondary_at_half_weight() {
+        let nudges = blended_trait_nudges("Fire", Some("Water"));
+        assert_eq!(nudges.get("aggression"), Some(&20));
+        assert_eq!(nudges.get("dominance"), Some(&15));
+        assert_eq!(nudges.get("patience"), Some(&10));
+        assert_eq!(nudges.get("friendliness"), Some(&8));
+
+        assert_eq!(blended_value_nudges("Fire", None), blended_value_nudges("Fire", Some("Unknown")));
+    }
+}
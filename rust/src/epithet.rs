@@ -0,0 +1,168 @@
+use crate::ability::AbilityKind;
+use crate::clan::DAYS_PER_YEAR;
+use crate::communication::{CommunicationTone, CommunicationType};
+use crate::dragon::InteractionResult;
+use crate::locale;
+use std::collections::HashMap;
+
+/// Days an oath of honor binds the dragon who swore it; an oath that lasts this long is kept
+pub const PROMISE_DAYS: u32 = DAYS_PER_YEAR;
+
+/// A title a dragon earns from its deeds, e.g. "the Oathkeeper"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Epithet {
+    Oathkeeper,
+    Oathbreaker,
+    Quarrelsome,
+    Kind,
+    Merry,
+    Healer,
+    Builder,
+    FarSighted,
+    Challenger,
+    Dread,
+    Serene,
+}
+
+impl Epithet {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Epithet::Oathkeeper => "oathkeeper",
+            Epithet::Oathbreaker => "oathbreaker",
+            Epithet::Quarrelsome => "quarrelsome",
+            Epithet::Kind => "kind",
+            Epithet::Merry => "merry",
+            Epithet::Healer => "healer",
+            Epithet::Builder => "builder",
+            Epithet::FarSighted => "far-sighted",
+            Epithet::Challenger => "challenger",
+            Epithet::Dread => "dread",
+            Epithet::Serene => "serene",
+        }
+    }
+
+    /// The epithet as it appears after a name, in the current locale
+    pub fn title(&self) -> String {
+        locale::render(&format!("epithet.{}", self.as_str()), &[])
+    }
+}
+
+/// Everything a dragon has done that can earn it an epithet
+#[derive(Debug, Clone, Default)]
+pub struct Deeds {
+    pub promises_kept: u32,
+    pub promises_broken: u32,
+    /// Confrontational communications started
+    pub confrontations: u32,
+    /// Friendly communications that were warmly received
+    pub kindnesses: u32,
+    /// Playful communications started
+    pub games: u32,
+    pub abilities_used: HashMap<AbilityKind, u32>,
+    /// Oaths of honor still binding, by the name of the dragon sworn to, with the day they were sworn
+    open_oaths: HashMap<String, u32>,
+}
+
+impl Deeds {
+    /// Note a communication this dragon started
+    /// Returns true if it broke an oath of honor to the receiver
+    pub fn record_communication(&mut self, receiver: &str, result: &InteractionResult, day: u32) -> bool {
+        let communication = result.communication();
+        match communication.comm_type {
+            CommunicationType::ValueAlignment if communication.primary_value.as_deref() == Some("honor") => {
+                self.open_oaths.entry(receiver.to_string()).or_insert(day);
+            }
+            CommunicationType::Confrontational => {
+                self.confrontations += 1;
+                if self.open_oaths.remove(receiver).is_some() {
+                    self.promises_broken += 1;
+                    return true;
+                }
+            }
+            CommunicationType::Friendly
+                if matches!(result.response_tone(), CommunicationTone::Positive | CommunicationTone::Warm) =>
+            {
+                self.kindnesses += 1;
+            }
+            CommunicationType::Playful => self.games += 1,
+            _ => {}
+        }
+        false
+    }
+
+    pub fn record_ability(&mut self, kind: AbilityKind) {
+        *self.abilities_used.entry(kind).or_insert(0) += 1;
+    }
+
+    /// Count every oath that has bound the dragon for PROMISE_DAYS as kept
+    pub fn settle_oaths(&mut self, today: u32) {
+        let before = self.open_oaths.len();
+        self.open_oaths.retain(|_, sworn_day| today - *sworn_day < PROMISE_DAYS);
+        self.promises_kept += (before - self.open_oaths.len()) as u32;
+    }
+
+    /// Drop oaths to a dragon that is no longer around to hold this dragon to them
+    pub fn release_oaths_to(&mut self, name: &str) {
+        self.open_oaths.remove(name);
+    }
+
+    fn ability_count(&self, kind: AbilityKind) -> u32 {
+        self.abilities_used.get(&kind).copied().unwrap_or(0)
+    }
+
+    /// The epithet the dragon's deeds have earned, if any
+    /// Each epithet needs a number of deeds; the one earned furthest past its threshold wins
+    pub fn epithet(&self) -> Option<Epithet> {
+        let candidates = [
+            (Epithet::Oathkeeper, self.promises_kept, 3),
+            (Epithet::Oathbreaker, self.promises_broken, 2),
+            (Epithet::Quarrelsome, self.confrontations, 10),
+            (Epithet::Kind, self.kindnesses, 10),
+            (Epithet::Merry, self.games, 10),
+            (Epithet::Healer, self.ability_count(AbilityKind::Healing), 3),
+            (Epithet::Builder, self.ability_count(AbilityKind::LairBuilding), 3),
+            (Epithet::FarSighted, self.ability_count(AbilityKind::Scouting), 3),
+            (Epithet::Challenger, self.ability_count(AbilityKind::LightningChallenge), 3),
+            (Epithet::Dread, self.ability_count(AbilityKind::IntimidationDisplay), 3),
+            (Epithet::Serene, self.ability_count(AbilityKind::Meditation), 3),
+        ];
+        let mut best: Option<(Epithet, u32, u32)> = None;
+        for (epithet, count, threshold) in candidates {
+            // Compare count / threshold without floating point; earlier epithets win ties
+            let better = count >= threshold
+                && best.is_none_or(|(_, best_count, best_threshold)| count * best_threshold > best_count * threshold);
+            if better {
+                best = Some((epithet, count, threshold));
+            }
+        }
+        best.map(|(epithet, _, _)| epithet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deeds_earn_epithets() {
+        let mut deeds = Deeds::default();
+        assert_eq!(deeds.epithet(), None);
+
+        for day in 0..3 {
+            deeds.open_oaths.insert(format!("Dragon{}", day), day);
+        }
+        deeds.settle_oaths(PROMISE_DAYS + 1);
+        assert_eq!(deeds.promises_kept, 2);
+        assert_eq!(deeds.epithet(), None);
+        deeds.settle_oaths(PROMISE_DAYS + 2);
+        assert_eq!(deeds.epithet(), Some(Epithet::Oathkeeper));
+
+        // Twelve confrontations (1.2x the threshold) don't outweigh four kept oaths (1.33x)
+        deeds.confrontations = 12;
+        deeds.promises_kept = 4;
+        assert_eq!(deeds.epithet(), Some(Epithet::Oathkeeper));
+        deeds.confrontations = 15;
+        assert_eq!(deeds.epithet(), Some(Epithet::Quarrelsome));
+        assert_eq!(Epithet::Quarrelsome.title(), "the Quarrelsome");
+    }
+}
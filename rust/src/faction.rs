@@ -0,0 +1,385 @@
+use crate::network::SocialGraph;
+use std::cmp::Reverse;
+
+/// Fewest dragons that make up a faction
+pub const MIN_FACTION_SIZE: usize = 3;
+
+/// Lowest average opinion a faction's members can hold of each other
+pub const FACTION_INTERNAL_OPINION: f64 = 25.0;
+
+/// How much higher a faction's internal opinion must be than the opinions between its members
+/// and the rest of the clan
+pub const FACTION_SEPARATION: f64 = 20.0;
+
+/// How many times as likely a dragon is to seek out a faction-mate as anyone else
+pub const FACTION_PARTNER_WEIGHT: u32 = 3;
+
+/// Opinion bonus when a dragon hears from a member of its own faction
+pub const FACTION_LOYALTY: i32 = 3;
+
+/// Opinion penalty when a dragon hears from a member of a rival faction
+pub const FACTION_HOSTILITY: i32 = 3;
+
+/// Days a faction can go undetected before it dissolves, so factions on the edge of the
+/// thresholds don't flicker in and out
+pub const FACTION_GRACE_DAYS: u32 = 5;
+
+/// Names tried for a new faction before falling back to numbering it
+const NAMING_ATTEMPTS: usize = 5;
+
+/// Groups of dragons, by index, that hold together as factions: communities of the social
+/// network that think well of each other and markedly less of everyone else
+pub fn detect(size: usize, opinions: &[i32]) -> Vec<Vec<usize>> {
+    let communities = SocialGraph::from_opinions(size, opinions).communities();
+    let count = communities.iter().max().map_or(0, |max| max + 1);
+    let opinion = |from: usize, to: usize| opinions[from * size + to] as f64;
+    let mean = |values: Vec<f64>| values.iter().sum::<f64>() / values.len() as f64;
+
+    (0..count)
+        .map(|community| (0..size).filter(|&i| communities[i] == community).collect::<Vec<usize>>())
+        .filter(|members| {
+            if members.len() < MIN_FACTION_SIZE || members.len() == size {
+                return false;
+            }
+            let outsiders: Vec<usize> = (0..size).filter(|i| !members.contains(i)).collect();
+            let internal = mean(
+                members
+                    .iter()
+                    .flat_map(|&i| members.iter().filter(move |&&j| j != i).map(move |&j| opinion(i, j)))
+                    .collect(),
+            );
+            let external = mean(
+                members
+                    .iter()
+                    .flat_map(|&i| outsiders.iter().flat_map(move |&j| [opinion(i, j), opinion(j, i)]))
+                    .collect(),
+            );
+            internal >= FACTION_INTERNAL_OPINION && internal - external >= FACTION_SEPARATION
+        })
+        .collect()
+}
+
+/// A named group of dragons that sticks together within the clan
+#[derive(Debug, Clone, PartialEq)]
+pub struct Faction {
+    pub id: u32,
+    pub name: String,
+    /// Member names, in clan order
+    pub members: Vec<String>,
+    pub formed_day: u32,
+    /// Members from each day the membership changed, starting with the founders
+    pub history: Vec<(u32, Vec<String>)>,
+    /// First day of the current run of days the faction has gone undetected
+    unseen_since: Option<u32>,
+}
+
+impl Faction {
+    fn new(id: u32, name: String, members: Vec<String>, day: u32) -> Self {
+        Faction {
+            id,
+            name,
+            history: vec![(day, members.clone())],
+            members,
+            formed_day: day,
+            unseen_since: None,
+        }
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.members.iter().any(|member| member == name)
+    }
+
+    fn set_members(&mut self, day: u32, members: Vec<String>) {
+        if members != self.members {
+            self.history.push((day, members.clone()));
+            self.members = members;
+        }
+    }
+
+    fn overlap(&self, group: &[String]) -> usize {
+        group.iter().filter(|name| self.contains(name)).count()
+    }
+
+    /// Whether a group is this faction carrying on: they share at least half of the smaller one
+    fn continues_in(&self, group: &[String]) -> bool {
+        let overlap = self.overlap(group);
+        overlap > 0 && 2 * overlap >= self.members.len().min(group.len())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FactionEventKind {
+    Formed,
+    Split,
+    Merged,
+    Dissolved,
+}
+
+impl FactionEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FactionEventKind::Formed => "formed",
+            FactionEventKind::Split => "split",
+            FactionEventKind::Merged => "merged",
+            FactionEventKind::Dissolved => "dissolved",
+        }
+    }
+}
+
+/// A change in the clan's factions
+#[derive(Debug, Clone, PartialEq)]
+pub struct FactionEvent {
+    pub kind: FactionEventKind,
+    pub day: u32,
+    /// The faction that formed, split, took others in or dissolved
+    pub faction: String,
+    /// Its members afterwards, or its last members if it dissolved
+    pub members: Vec<String>,
+    /// Factions that split off from it, or that merged into it
+    pub others: Vec<String>,
+}
+
+/// The clan's factions and the changes to them not yet reported
+#[derive(Debug, Clone, Default)]
+pub struct Factions {
+    factions: Vec<Faction>,
+    next_id: u32,
+    events: Vec<FactionEvent>,
+}
+
+impl Factions {
+    /// Current factions, oldest first
+    pub fn all(&self) -> &[Faction] {
+        &self.factions
+    }
+
+    pub fn get(&self, id: u32) -> Option<&Faction> {
+        self.factions.iter().find(|faction| faction.id == id)
+    }
+
+    pub fn faction_of(&self, name: &str) -> Option<&Faction> {
+        self.factions.iter().find(|faction| faction.contains(name))
+    }
+
+    /// Opinion modifier for a dragon hearing from another: loyalty to its own faction,
+    /// hostility to a rival one, and nothing if either is unaligned
+    pub fn opinion_modifier(&self, receiver: &str, sender: &str) -> i32 {
+        match (self.faction_of(receiver), self.faction_of(sender)) {
+            (Some(a), Some(b)) if a.id == b.id => FACTION_LOYALTY,
+            (Some(_), Some(_)) => -FACTION_HOSTILITY,
+            _ => 0,
+        }
+    }
+
+    /// Match the factions to the groups detected today, carrying each faction on in the group
+    /// that shares most of its members
+    /// A faction whose members scatter into several groups splits, factions that end up in one
+    /// group merge into the one that contributed most, and groups no faction carries on in
+    /// form new factions named by name_for
+    pub fn update(&mut self, day: u32, groups: Vec<Vec<String>>, mut name_for: impl FnMut(&[String]) -> String) {
+        let old = std::mem::take(&mut self.factions);
+
+        // Each group is carried on by the faction it shares most members with; older factions win ties
+        let heirs: Vec<Option<usize>> = groups
+            .iter()
+            .map(|group| {
+                (0..old.len())
+                    .filter(|&f| old[f].continues_in(group))
+                    .max_by_key(|&f| (old[f].overlap(group), Reverse(f)))
+            })
+            .collect();
+
+        let mut next: Vec<Faction> = Vec::new();
+        let mut continued_as: Vec<Option<usize>> = vec![None; old.len()];
+        let mut events = Vec::new();
+        for (f, faction) in old.iter().enumerate() {
+            let mut inherited: Vec<usize> = (0..groups.len()).filter(|&g| heirs[g] == Some(f)).collect();
+            if inherited.is_empty() {
+                continue;
+            }
+            // The largest group keeps the faction; the rest split off as new ones
+            inherited.sort_by_key(|&g| Reverse(groups[g].len()));
+            let mut continued = faction.clone();
+            continued.unseen_since = None;
+            continued.set_members(day, groups[inherited[0]].clone());
+            continued_as[f] = Some(next.len());
+            next.push(continued);
+
+            let mut splinters = Vec::new();
+            for &g in &inherited[1..] {
+                let splinter = self.found(&[&old, &next], day, groups[g].clone(), &mut name_for);
+                splinters.push(splinter.name.clone());
+                next.push(splinter);
+            }
+            if !splinters.is_empty() {
+                let parent = &next[continued_as[f].unwrap()];
+                events.push(FactionEvent {
+                    kind: FactionEventKind::Split,
+                    day,
+                    faction: parent.name.clone(),
+                    members: parent.members.clone(),
+                    others: splinters,
+                });
+            }
+        }
+
+        // Factions that carry on in no group either merged into another, are lying low, or dissolved
+        let mut merged_into: Vec<(usize, Vec<String>)> = Vec::new();
+        for (faction, _) in old.iter().zip(&continued_as).filter(|(_, continued)| continued.is_none()) {
+            let destination = (0..groups.len())
+                .filter(|&g| faction.continues_in(&groups[g]))
+                .max_by_key(|&g| (faction.overlap(&groups[g]), Reverse(g)))
+                .and_then(|g| heirs[g])
+                .and_then(|heir| continued_as[heir]);
+            match destination {
+                Some(into) => match merged_into.iter_mut().find(|(i, _)| *i == into) {
+                    Some((_, absorbed)) => absorbed.push(faction.name.clone()),
+                    None => merged_into.push((into, vec![faction.name.clone()])),
+                },
+                None => {
+                    // Members who have gone over to another group leave it
+                    let unseen_since = faction.unseen_since.unwrap_or(day);
+                    let remaining: Vec<String> =
+                        faction.members.iter().filter(|member| !groups.iter().any(|group| group.contains(member))).cloned().collect();
+                    if day - unseen_since < FACTION_GRACE_DAYS && !remaining.is_empty() {
+                        let mut lying_low = faction.clone();
+                        lying_low.unseen_since = Some(unseen_since);
+                        lying_low.set_members(day, remaining);
+                        next.push(lying_low);
+                    } else {
+                        events.push(FactionEvent {
+                            kind: FactionEventKind::Dissolved,
+                            day,
+                            faction: faction.name.clone(),
+                            members: faction.members.clone(),
+                            others: Vec::new(),
+                        });
+                    }
+                }
+            }
+        }
+        for (into, absorbed) in merged_into {
+            events.push(FactionEvent {
+                kind: FactionEventKind::Merged,
+                day,
+                faction: next[into].name.clone(),
+                members: next[into].members.clone(),
+                others: absorbed,
+            });
+        }
+
+        for (group, _) in groups.into_iter().zip(heirs).filter(|(_, heir)| heir.is_none()) {
+            let faction = self.found(&[&old, &next], day, group, &mut name_for);
+            events.push(FactionEvent {
+                kind: FactionEventKind::Formed,
+                day,
+                faction: faction.name.clone(),
+                members: faction.members.clone(),
+                others: Vec::new(),
+            });
+            next.push(faction);
+        }
+
+        next.sort_by_key(|faction| faction.id);
+        self.factions = next;
+        self.events.extend(events);
+    }
+
+    /// Start a new faction, with a name none of the existing factions has
+    fn found(
+        &mut self,
+        existing: &[&[Faction]],
+        day: u32,
+        members: Vec<String>,
+        name_for: &mut impl FnMut(&[String]) -> String,
+    ) -> Faction {
+        self.next_id += 1;
+        let taken = |name: &str| existing.iter().flat_map(|factions| factions.iter()).any(|faction| faction.name == name);
+        let mut name = name_for(&members);
+        for _ in 1..NAMING_ATTEMPTS {
+            if !taken(&name) {
+                break;
+            }
+            name = name_for(&members);
+        }
+        if taken(&name) {
+            name = format!("{} {}", name, self.next_id);
+        }
+        Faction::new(self.next_id, name, members, day)
+    }
+
+    /// Take a dragon out of its faction, e.g. when it leaves the clan
+    pub fn remove_member(&mut self, day: u32, name: &str) {
+        for faction in self.factions.iter_mut().filter(|faction| faction.contains(name)) {
+            let members = faction.members.iter().filter(|member| *member != name).cloned().collect();
+            faction.set_members(day, members);
+        }
+    }
+
+    /// Changes since they were last taken, in the order they happened
+    pub fn take_events(&mut self) -> Vec<FactionEvent> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_factions_form_merge_split_and_dissolve() {
+        // Dragons 0-2 and 3-5 are close within each group and cold across them; 6 likes no one
+        let size = 7;
+        let mut opinions = vec![-20; size * size];
+        for group in [[0, 1, 2], [3, 4, 5]] {
+            for &i in &group {
+                for &j in &group {
+                    opinions[i * size + j] = 70;
+                }
+            }
+        }
+        assert_eq!(detect(size, &opinions), vec![vec![0, 1, 2], vec![3, 4, 5]]);
+
+        let mut count = 0;
+        let mut name_for = |_: &[String]| {
+            count += 1;
+            format!("Faction {}", count)
+        };
+        let mut factions = Factions::default();
+        factions.update(1, vec![names(&["A", "B", "C"]), names(&["D", "E", "F"])], &mut name_for);
+        let formed = factions.take_events();
+        assert_eq!(formed.iter().map(|e| e.kind).collect::<Vec<_>>(), [FactionEventKind::Formed; 2]);
+        assert_eq!(factions.opinion_modifier("A", "B"), FACTION_LOYALTY);
+        assert_eq!(factions.opinion_modifier("A", "D"), -FACTION_HOSTILITY);
+        assert_eq!(factions.opinion_modifier("A", "G"), 0);
+
+        // The two groups come together; the older faction takes the other in
+        factions.update(2, vec![names(&["A", "B", "C", "D", "E", "F"])], &mut name_for);
+        let merged = factions.take_events();
+        assert_eq!(merged.len(), 1);
+        assert_eq!((merged[0].kind, merged[0].faction.as_str()), (FactionEventKind::Merged, "Faction 1"));
+        assert_eq!(merged[0].others, ["Faction 2"]);
+        assert_eq!(factions.all().len(), 1);
+
+        // It falls apart again, leaving the larger half under the old name
+        factions.update(3, vec![names(&["A", "B"]), names(&["C", "D", "E", "F"])], &mut name_for);
+        let split = factions.take_events();
+        assert_eq!((split[0].kind, split[0].faction.as_str()), (FactionEventKind::Split, "Faction 1"));
+        assert_eq!(factions.faction_of("C").unwrap().id, 1);
+        assert_eq!(factions.get(1).unwrap().history.len(), 3);
+
+        // Factions lie low for a few days before they dissolve
+        factions.update(4, Vec::new(), &mut name_for);
+        assert!(factions.take_events().is_empty());
+        assert_eq!(factions.all().len(), 2);
+        factions.update(4 + FACTION_GRACE_DAYS, Vec::new(), &mut name_for);
+        let dissolved = factions.take_events();
+        assert!(dissolved.iter().all(|e| e.kind == FactionEventKind::Dissolved));
+        assert_eq!(dissolved.len(), 2);
+        assert!(factions.all().is_empty());
+    }
+}
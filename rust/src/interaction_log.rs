@@ -0,0 +1,194 @@
+use crate::recorder::RingBuffer;
+use serde::Serialize;
+
+/// Entries kept in the interaction log unless configured otherwise
+pub const DEFAULT_LOG_CAPACITY: usize = 1000;
+
+/// Columns of an exported log, in order
+const CSV_COLUMNS: [&str; 13] = [
+    "sequence",
+    "time",
+    "day",
+    "sender",
+    "receiver",
+    "comm_type",
+    "ability",
+    "primary_value",
+    "primary_trait",
+    "tone",
+    "intensity",
+    "sender_opinion_change",
+    "receiver_opinion_change",
+];
+
+/// Format to export the interaction log in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Comma-separated values with a header row
+    Csv,
+    /// One JSON object per line
+    JsonLines,
+}
+
+impl LogFormat {
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "csv" => Some(LogFormat::Csv),
+            "jsonl" => Some(LogFormat::JsonLines),
+            _ => None,
+        }
+    }
+}
+
+/// One interaction as the log keeps it
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LogEntry {
+    /// Position in the clan's history, counting from 1; entries dropped from a full log leave gaps
+    pub sequence: u64,
+    /// Time in days, e.g. 3.5 for halfway through day 3
+    pub time: f64,
+    pub day: u32,
+    pub sender: String,
+    /// None for abilities without a target
+    pub receiver: Option<String>,
+    /// Type of communication, or None for an ability use
+    pub comm_type: Option<String>,
+    pub ability: Option<String>,
+    pub primary_value: Option<String>,
+    pub primary_trait: Option<String>,
+    /// Tone of the receiver's response
+    pub tone: Option<String>,
+    pub intensity: Option<u32>,
+    /// How the sender's opinion of the receiver changed
+    pub sender_opinion_change: i32,
+    /// How the receiver's opinion of the sender changed
+    pub receiver_opinion_change: i32,
+}
+
+impl LogEntry {
+    fn csv_row(&self) -> String {
+        let optional = |field: &Option<String>| field.as_deref().map(csv_field).unwrap_or_default();
+        [
+            self.sequence.to_string(),
+            self.time.to_string(),
+            self.day.to_string(),
+            csv_field(&self.sender),
+            optional(&self.receiver),
+            optional(&self.comm_type),
+            optional(&self.ability),
+            optional(&self.primary_value),
+            optional(&self.primary_trait),
+            optional(&self.tone),
+            self.intensity.map(|i| i.to_string()).unwrap_or_default(),
+            self.sender_opinion_change.to_string(),
+            self.receiver_opinion_change.to_string(),
+        ]
+        .join(",")
+    }
+}
+
+/// Quote a CSV field if it contains a comma, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// The clan's most recent interactions, oldest first
+#[derive(Debug, Clone)]
+pub struct InteractionLog {
+    entries: RingBuffer<LogEntry>,
+    recorded: u64,
+}
+
+impl Default for InteractionLog {
+    fn default() -> Self {
+        InteractionLog {
+            entries: RingBuffer::new(DEFAULT_LOG_CAPACITY),
+            recorded: 0,
+        }
+    }
+}
+
+impl InteractionLog {
+    /// Add an entry, numbering it and dropping the oldest entry if the log is full
+    pub fn record(&mut self, mut entry: LogEntry) {
+        self.recorded += 1;
+        entry.sequence = self.recorded;
+        self.entries.push(entry);
+    }
+
+    /// Change how many entries the log keeps (at least 1), dropping the oldest if it shrinks
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.entries.set_capacity(capacity.max(1));
+    }
+
+    /// Drop every entry, keeping the capacity
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recorded = 0;
+    }
+
+    pub fn export(&self, format: LogFormat) -> String {
+        let entries = self.entries.to_vec();
+        let lines: Vec<String> = match format {
+            LogFormat::Csv => std::iter::once(CSV_COLUMNS.join(","))
+                .chain(entries.iter().map(LogEntry::csv_row))
+                .collect(),
+            LogFormat::JsonLines => entries
+                .iter()
+                .map(|entry| serde_json::to_string(entry).expect("log entries serialize to JSON"))
+                .collect(),
+        };
+        lines.iter().map(|line| format!("{}\n", line)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(sender: &str, receiver: &str) -> LogEntry {
+        LogEntry {
+            sequence: 0,
+            time: 2.5,
+            day: 2,
+            sender: sender.to_string(),
+            receiver: Some(receiver.to_string()),
+            comm_type: Some("friendly".to_string()),
+            ability: None,
+            primary_value: None,
+            primary_trait: Some("friendliness".to_string()),
+            tone: Some("warm".to_string()),
+            intensity: Some(60),
+            sender_opinion_change: 2,
+            receiver_opinion_change: -1,
+        }
+    }
+
+    #[test]
+    fn test_log_exports_latest_entries() {
+        let mut log = InteractionLog::default();
+        log.set_capacity(2);
+        log.record(entry("Ignis", "Aqua"));
+        log.record(entry("Aqua", "Terra"));
+        log.record(entry("Terra, the \"Old\"", "Ignis"));
+
+        let csv = log.export(LogFormat::Csv);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], CSV_COLUMNS.join(","));
+        assert_eq!(lines[1], "2,2.5,2,Aqua,Terra,friendly,,,friendliness,warm,60,2,-1");
+        assert!(lines[2].starts_with("3,2.5,2,\"Terra, the \"\"Old\"\"\",Ignis,"));
+
+        let jsonl = log.export(LogFormat::JsonLines);
+        let first: serde_json::Value = serde_json::from_str(jsonl.lines().next().unwrap()).unwrap();
+        assert_eq!(first["sequence"], 2);
+        assert_eq!(first["primary_value"], serde_json::Value::Null);
+        assert_eq!(first["receiver_opinion_change"], -1);
+        assert_eq!(LogFormat::from_str("xml"), None);
+    }
+}
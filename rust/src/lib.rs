@@ -4,16 +4,33 @@ mod dragon;
 mod character;
 mod values;
 mod relationship;
+mod memory;
+mod bond;
+mod belief;
+mod element;
+mod affinity;
+mod ability;
+mod template;
+mod locale;
 mod communication;
+mod epithet;
+mod chronicle;
+mod interaction_log;
+mod clan_name;
 mod clan;
+mod markov;
+mod name_style;
 mod name_generator;
+mod faction;
+mod network;
 mod notification;
+mod recorder;
+mod stats;
 mod clan_service;
 
 // Export only what the UI needs - hide internal implementation
-pub use dragon::DragonElement; // Type definitions for elements
 pub use dragon::InteractionResult; // Used internally by clan_service, kept for compatibility
-pub use name_generator::{generate_dragon_name, generate_clan_name};
+pub use name_generator::{generate_dragon_name, generate_markov_name, generate_clan_name};
 
 // Export the service - this is the main interface
 pub use clan_service::*;
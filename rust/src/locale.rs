@@ -0,0 +1,185 @@
+use crate::template::{choose, fill, Template, TemplateLibrary};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Locale used when nothing else is selected, and for keys a catalog is missing
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Built-in catalogs by locale, embedded at compile time
+const BUILT_IN_CATALOGS: [(&str, &str); 2] = [
+    ("en", include_str!("../data/locales/en.json")),
+    ("es", include_str!("../data/locales/es.json")),
+];
+
+/// Message catalogs for every known locale, and which one is in use
+#[derive(Debug, Clone)]
+pub struct Catalogs {
+    current: String,
+    catalogs: HashMap<String, TemplateLibrary>,
+}
+
+impl Catalogs {
+    /// Variants for a key in the current locale, falling back to the default locale
+    fn variants(&self, key: &str) -> Option<&[Template]> {
+        [self.current.as_str(), DEFAULT_LOCALE]
+            .iter()
+            .filter_map(|locale| self.catalogs.get(*locale))
+            .find_map(|catalog| catalog.variants(key))
+    }
+}
+
+impl Default for Catalogs {
+    fn default() -> Self {
+        let catalogs = BUILT_IN_CATALOGS
+            .iter()
+            .map(|(locale, json)| {
+                let mut catalog = TemplateLibrary::default();
+                catalog.register(TemplateLibrary::from_json(json).expect("built-in catalogs are valid"));
+                (locale.to_string(), catalog)
+            })
+            .collect();
+        Catalogs {
+            current: DEFAULT_LOCALE.to_string(),
+            catalogs,
+        }
+    }
+}
+
+/// Plural category of count in a locale: "one" or "other"
+pub fn plural_category(locale: &str, count: u32) -> &'static str {
+    let language = locale.split(['-', '_']).next().unwrap_or(locale);
+    let is_one = match language {
+        // Zero counts as singular in French and Portuguese
+        "fr" | "pt" => count <= 1,
+        _ => count == 1,
+    };
+    if is_one {
+        "one"
+    } else {
+        "other"
+    }
+}
+
+// Global message catalogs
+// For WASM (single-threaded), we use a simple thread_local with RefCell
+thread_local! {
+    static CATALOGS: RefCell<Catalogs> = RefCell::new(Catalogs::default());
+}
+
+/// Render a random variant of the message for key in the current locale
+/// Returns None if no catalog has a template for it
+pub fn lookup(key: &str, placeholders: &[(&str, &str)]) -> Option<String> {
+    CATALOGS.with(|catalogs| {
+        catalogs
+            .borrow()
+            .variants(key)
+            .and_then(choose)
+            .map(|template| fill(&template.text, placeholders))
+    })
+}
+
+/// Render a random variant of the message for key in the current locale
+/// Returns the key itself if there is no template for it
+pub fn render(key: &str, placeholders: &[(&str, &str)]) -> String {
+    lookup(key, placeholders).unwrap_or_else(|| key.to_string())
+}
+
+/// Render the message for key with the plural form matching count, e.g. "relationship.info.one"
+/// count is also available to the template as {count}
+pub fn render_plural(key: &str, count: u32, placeholders: &[(&str, &str)]) -> String {
+    let category = plural_category(&current_locale(), count);
+    let count = count.to_string();
+    let mut placeholders = placeholders.to_vec();
+    placeholders.push(("count", &count));
+    render(&format!("{}.{}", key, category), &placeholders)
+}
+
+/// Localized name of a value, trait or element, e.g. name("value", "honor")
+/// Names the catalogs don't know, such as custom elements, are returned unchanged
+pub fn name(kind: &str, name: &str) -> String {
+    lookup(&format!("{}.{}", kind, name), &[]).unwrap_or_else(|| name.to_string())
+}
+
+pub fn current_locale() -> String {
+    CATALOGS.with(|catalogs| catalogs.borrow().current.clone())
+}
+
+/// Locales with a catalog, sorted
+pub fn locales() -> Vec<String> {
+    CATALOGS.with(|catalogs| {
+        let mut locales: Vec<String> = catalogs.borrow().catalogs.keys().cloned().collect();
+        locales.sort();
+        locales
+    })
+}
+
+/// Switch the locale used for all generated text
+pub fn set_locale(locale: &str) -> Result<(), String> {
+    CATALOGS.with(|catalogs| {
+        let mut catalogs = catalogs.borrow_mut();
+        if !catalogs.catalogs.contains_key(locale) {
+            return Err(format!("no catalog for locale '{}'", locale));
+        }
+        catalogs.current = locale.to_string();
+        Ok(())
+    })
+}
+
+/// Load templates from JSON into a locale's catalog, creating the catalog if it is new
+/// Keys it defines replace that locale's variants; returns the number of keys loaded
+pub fn load_catalog(locale: &str, json: &str) -> Result<usize, String> {
+    if locale.trim().is_empty() {
+        return Err("locale must not be empty".to_string());
+    }
+    let templates = TemplateLibrary::from_json(json)?;
+    let count = templates.len();
+    CATALOGS.with(|catalogs| {
+        catalogs
+            .borrow_mut()
+            .catalogs
+            .entry(locale.to_string())
+            .or_default()
+            .register(templates)
+    });
+    Ok(count)
+}
+
+/// Restore the built-in catalogs, keeping the current locale if it is still available
+pub fn reset_catalogs() {
+    CATALOGS.with(|catalogs| {
+        let mut catalogs = catalogs.borrow_mut();
+        let current = catalogs.current.clone();
+        *catalogs = Catalogs::default();
+        if catalogs.catalogs.contains_key(&current) {
+            catalogs.current = current;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locale_switch_and_pluralization() {
+        assert_eq!(render_plural("relationship.info", 1, &[("status", "🙂"), ("description", "friendly"), ("opinion", "25")]), "🙂 friendly (25/100, 1 interaction)");
+        assert_eq!(name("value", "wisdom"), "wisdom");
+
+        set_locale("es").unwrap();
+        assert_eq!(render_plural("relationship.info", 3, &[("status", "🙂"), ("description", "amistoso"), ("opinion", "25")]), "🙂 amistoso (25/100, 3 interacciones)");
+        assert_eq!(name("value", "wisdom"), "sabiduría");
+        assert_eq!(name("element", "Shadow"), "Shadow");
+
+        // Keys missing from a catalog fall back to English
+        load_catalog("pirate", r#"{"templates": {"value.honor": [{"text": "code"}]}}"#).unwrap();
+        set_locale("pirate").unwrap();
+        assert_eq!(name("value", "honor"), "code");
+        assert_eq!(name("value", "wisdom"), "wisdom");
+        assert!(set_locale("xx").is_err());
+
+        reset_catalogs();
+        assert_eq!(current_locale(), DEFAULT_LOCALE);
+        assert_eq!(plural_category("fr", 0), "one");
+        assert_eq!(plural_category("en", 0), "other");
+    }
+}
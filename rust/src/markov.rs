@@ -0,0 +1,159 @@
+use rand::Rng;
+use std::collections::{BTreeMap, HashSet};
+
+/// Letters of context the model looks at when picking the next letter
+pub const MARKOV_ORDER: usize = 2;
+
+/// Shortest and longest names the generator accepts
+pub const MIN_NAME_LENGTH: usize = 4;
+pub const MAX_NAME_LENGTH: usize = 10;
+
+/// Names to try before giving up on a corpus
+const MAX_ATTEMPTS: usize = 50;
+
+/// Pads the start of a name so the first letters have context
+const START: char = '^';
+/// Marks the end of a name
+const END: char = '$';
+
+const VOWELS: &str = "aeiouy";
+
+/// Character n-gram statistics learned from a corpus of names
+#[derive(Debug, Clone)]
+pub struct MarkovModel {
+    order: usize,
+    /// How often each letter follows each context of `order` letters
+    transitions: BTreeMap<String, BTreeMap<char, u32>>,
+}
+
+impl MarkovModel {
+    /// Learn which letters follow which from every name in corpus
+    pub fn train(corpus: &[String], order: usize) -> Self {
+        let mut transitions: BTreeMap<String, BTreeMap<char, u32>> = BTreeMap::new();
+        for name in corpus {
+            let letters: Vec<char> = std::iter::repeat_n(START, order)
+                .chain(name.to_lowercase().chars().filter(|c| c.is_alphabetic()))
+                .chain(std::iter::once(END))
+                .collect();
+            for window in letters.windows(order + 1) {
+                let context: String = window[..order].iter().collect();
+                *transitions.entry(context).or_default().entry(window[order]).or_insert(0) += 1;
+            }
+        }
+        MarkovModel { order, transitions }
+    }
+
+    /// Walk the model from the start of a name until it ends or reaches max_length letters
+    /// Returns None if the walk runs past max_length or into a context it never saw
+    pub fn generate(&self, rng: &mut impl Rng, max_length: usize) -> Option<String> {
+        let mut letters: Vec<char> = vec![START; self.order];
+        loop {
+            let context: String = letters[letters.len() - self.order..].iter().collect();
+            let followers = self.transitions.get(&context)?;
+            let total: u32 = followers.values().sum();
+            let mut roll = rng.gen_range(0..total);
+            let next = *followers
+                .iter()
+                .find(|(_, count)| {
+                    if roll < **count {
+                        true
+                    } else {
+                        roll -= **count;
+                        false
+                    }
+                })?
+                .0;
+            if next == END {
+                return Some(letters[self.order..].iter().collect());
+            }
+            letters.push(next);
+            if letters.len() - self.order > max_length {
+                return None;
+            }
+        }
+    }
+}
+
+/// Whether a lowercase name is easy to say
+/// It needs a vowel, at most two vowels or two consonants in a row (counting th, ch, sh and ph
+/// as one consonant), and no letter three times running
+pub fn is_pronounceable(name: &str) -> bool {
+    let letters: Vec<char> = name.chars().collect();
+    if !letters.iter().any(|c| VOWELS.contains(*c)) {
+        return false;
+    }
+
+    let mut vowel_run = 0;
+    let mut consonant_run = 0;
+    for (i, c) in letters.iter().enumerate() {
+        if i >= 2 && letters[i - 1] == *c && letters[i - 2] == *c {
+            return false;
+        }
+        if VOWELS.contains(*c) {
+            vowel_run += 1;
+            consonant_run = 0;
+        } else {
+            let digraph = *c == 'h' && i > 0 && "tcsp".contains(letters[i - 1]);
+            if !digraph {
+                consonant_run += 1;
+            }
+            vowel_run = 0;
+        }
+        if vowel_run > 2 || consonant_run > 2 {
+            return false;
+        }
+    }
+    true
+}
+
+/// Generate a new, pronounceable name in the style of corpus
+/// Names already in the corpus are rejected so the generator invents rather than repeats
+/// Returns None if the corpus is empty or no acceptable name turns up
+pub fn generate_name(corpus: &[String], rng: &mut impl Rng) -> Option<String> {
+    if corpus.is_empty() {
+        return None;
+    }
+    let model = MarkovModel::train(corpus, MARKOV_ORDER);
+    let known: HashSet<String> = corpus.iter().map(|name| name.to_lowercase()).collect();
+
+    (0..MAX_ATTEMPTS)
+        .filter_map(|_| model.generate(rng, MAX_NAME_LENGTH))
+        .find(|name| name.chars().count() >= MIN_NAME_LENGTH && is_pronounceable(name) && !known.contains(name))
+        .map(|name| {
+            let mut chars = name.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_generated_names_are_new_and_pronounceable() {
+        let corpus: Vec<String> = ["Ignatius", "Pyralis", "Cinderax", "Emberly", "Infernis", "Vulcara", "Ashkar", "Brandor"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        let mut rng = StdRng::seed_from_u64(7);
+
+        for _ in 0..20 {
+            let name = generate_name(&corpus, &mut rng).unwrap();
+            let lower = name.to_lowercase();
+            assert!(name.chars().next().unwrap().is_uppercase());
+            assert!((MIN_NAME_LENGTH..=MAX_NAME_LENGTH).contains(&lower.chars().count()));
+            assert!(is_pronounceable(&lower), "{} is hard to say", name);
+            assert!(!corpus.iter().any(|known| known.to_lowercase() == lower));
+        }
+
+        assert!(!is_pronounceable("strk"));
+        assert!(!is_pronounceable("aaargh"));
+        assert!(is_pronounceable("thalor"));
+        assert!(generate_name(&[], &mut rng).is_none());
+    }
+}
@@ -0,0 +1,157 @@
+use crate::communication::{CommunicationTone, CommunicationType};
+use crate::locale;
+
+/// Maximum number of memories a single dragon keeps
+pub const MEMORY_CAPACITY: usize = 20;
+
+/// Minimum absolute opinion change for an interaction to be remembered
+const SIGNIFICANT_OPINION_CHANGE: i32 = 8;
+
+/// A single remembered interaction, from the perspective of the dragon holding it
+#[derive(Debug, Clone)]
+pub struct Memory {
+    /// Name of the other dragon involved
+    pub other_name: String,
+    /// Whether the remembering dragon started the interaction
+    pub initiated: bool,
+    /// Type of communication that was sent
+    pub comm_type: CommunicationType,
+    /// Tone of the response to the communication
+    pub tone: CommunicationTone,
+    /// How the remembering dragon's opinion changed
+    pub opinion_change: i32,
+    /// Clan day on which the interaction happened
+    pub day: u32,
+}
+
+impl Memory {
+    /// Whether an interaction is notable enough to be remembered
+    /// Confrontations are always remembered, other interactions only if they moved the opinion noticeably
+    pub fn is_significant(
+        comm_type: CommunicationType,
+        tone: CommunicationTone,
+        opinion_change: i32,
+    ) -> bool {
+        comm_type == CommunicationType::Confrontational
+            || tone == CommunicationTone::Challenging
+            || opinion_change.abs() >= SIGNIFICANT_OPINION_CHANGE
+    }
+
+    /// Human readable summary of the memory, in the current locale
+    pub fn describe(&self) -> String {
+        let key = if self.initiated { "memory.initiated" } else { "memory.received" };
+        locale::render(
+            key,
+            &[
+                ("day", &self.day.to_string()),
+                ("other", &self.other_name),
+                ("comm_type", &locale::name("comm_type", &self.comm_type.as_str().replace(' ', "_"))),
+                ("tone", &locale::name("tone", self.tone.as_str())),
+                ("change", &format!("{:+}", self.opinion_change)),
+            ],
+        )
+    }
+}
+
+/// Bounded store of a dragon's notable interactions
+/// When full, the least impactful memory is forgotten first
+#[derive(Debug, Clone)]
+pub struct EpisodicMemory {
+    memories: Vec<Memory>,
+    capacity: usize,
+}
+
+impl EpisodicMemory {
+    pub fn new(capacity: usize) -> Self {
+        EpisodicMemory {
+            memories: Vec::new(),
+            capacity,
+        }
+    }
+
+    /// Record a memory, evicting the weakest one if the store is full
+    /// Returns false if the memory was weaker than everything already remembered
+    pub fn record(&mut self, memory: Memory) -> bool {
+        if self.capacity == 0 {
+            return false;
+        }
+
+        if self.memories.len() >= self.capacity {
+            // Oldest memory wins ties, so the weakest old memory is forgotten first
+            let weakest = self
+                .memories
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, m)| m.opinion_change.abs())
+                .map(|(i, m)| (i, m.opinion_change.abs()));
+
+            match weakest {
+                Some((index, strength)) if strength <= memory.opinion_change.abs() => {
+                    self.memories.remove(index);
+                }
+                _ => return false,
+            }
+        }
+
+        self.memories.push(memory);
+        true
+    }
+
+    /// All memories, oldest first
+    pub fn memories(&self) -> &[Memory] {
+        &self.memories
+    }
+
+    /// Memories involving a specific dragon, oldest first
+    pub fn about(&self, other_name: &str) -> Vec<&Memory> {
+        self.memories
+            .iter()
+            .filter(|m| m.other_name == other_name)
+            .collect()
+    }
+}
+
+impl Default for EpisodicMemory {
+    fn default() -> Self {
+        EpisodicMemory::new(MEMORY_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memory(other_name: &str, opinion_change: i32, day: u32) -> Memory {
+        Memory {
+            other_name: other_name.to_string(),
+            initiated: true,
+            comm_type: CommunicationType::Friendly,
+            tone: CommunicationTone::Warm,
+            opinion_change,
+            day,
+        }
+    }
+
+    #[test]
+    fn test_weakest_memory_is_forgotten_first() {
+        let mut store = EpisodicMemory::new(2);
+        assert!(store.record(memory("Ignis", -15, 1)));
+        assert!(store.record(memory("Aqua", 9, 2)));
+
+        // Stronger than the weakest memory, so Aqua is forgotten
+        assert!(store.record(memory("Terra", 12, 3)));
+        let names: Vec<&str> = store.memories().iter().map(|m| m.other_name.as_str()).collect();
+        assert_eq!(names, vec!["Ignis", "Terra"]);
+
+        // Weaker than everything remembered, so it is dropped
+        assert!(!store.record(memory("Zephyr", 8, 4)));
+        assert_eq!(store.memories().len(), 2);
+        assert_eq!(store.about("Ignis").len(), 1);
+
+        let ignis = memory("Ignis", -15, 1);
+        assert_eq!(ignis.describe(), "Day 1: approached Ignis (friendly) and got a warm response (-15)");
+        locale::set_locale("es").unwrap();
+        assert_eq!(ignis.describe(), "Día 1: se acercó a Ignis (cordialidad) y recibió una respuesta cálida (-15)");
+        locale::set_locale(locale::DEFAULT_LOCALE).unwrap();
+    }
+}
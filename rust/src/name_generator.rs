@@ -1,145 +1,145 @@
 use wasm_bindgen::prelude::*;
-use crate::dragon::{DragonElement, element_from_str};
+use crate::element;
+use crate::markov;
+use crate::name_style::{self, NameStyle, DEFAULT_NAME_STYLE};
+use rand::seq::SliceRandom;
 use rand::Rng;
 
-const NAME_SYLLABLES: &[&str] = &[
-    "Aer", "Ign", "Aqu", "Terr", "Zeph", "Cryo", "Pyro", "Nyx", "Lux", "Umbr",
-    "Dra", "Vor", "Kyr", "Zar", "Xen", "Nex", "Rex", "Vex", "Zor", "Kor",
-    "Thal", "Mal", "Val", "Gal", "Kal", "Tal", "Sal", "Dal", "Fal", "Hal"
-];
-
-const NAME_MIDDLES: &[&str] = &[
-    "on", "en", "in", "an", "un", "ar", "or", "ir", "ur", "er",
-    "ath", "eth", "ith", "oth", "uth", "ach", "ech", "ich", "och", "uch",
-    "ra", "la", "na", "ma", "ta", "sa", "da", "fa", "ga", "ka"
-];
-
-const NAME_SUFFIXES: &[&str] = &[
-    "is", "us", "as", "os", "es", "ix", "ax", "ox", "ex", "yx",
-    "ion", "eon", "ian", "ean", "oan", "urn", "orn", "arn", "ern", "urn",
-    "th", "nth", "rth", "lth", "mth", "dra", "ra", "la", "na", "ma"
-];
-
-struct ElementNames {
-    prefixes: &'static [&'static str],
-    suffixes: &'static [&'static str],
+/// How dragon names are made up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NameMethod {
+    /// Join a prefix, optional middle and suffix from the clan's name style
+    #[default]
+    Syllable,
+    /// Invent names from letter patterns learned from each element's seed names
+    Markov,
 }
 
-const ELEMENT_NAMES: &[(DragonElement, ElementNames)] = &[
-    (
-        DragonElement::Fire,
-        ElementNames {
-            prefixes: &["Ign", "Pyro", "Flar", "Blaz", "Ember", "Scorch", "Infer", "Cind"],
-            suffixes: &["is", "ion", "ra", "th", "ix", "ax"],
-        },
-    ),
-    (
-        DragonElement::Water,
-        ElementNames {
-            prefixes: &["Aqu", "Hydr", "Mar", "Tid", "Flow", "Riv", "Oce", "Wav"],
-            suffixes: &["a", "ia", "is", "us", "an", "en"],
-        },
-    ),
-    (
-        DragonElement::Earth,
-        ElementNames {
-            prefixes: &["Terr", "Ston", "Rock", "Cryst", "Gran", "Clay", "Mud", "Grav"],
-            suffixes: &["a", "is", "us", "an", "on", "th"],
-        },
-    ),
-    (
-        DragonElement::Wind,
-        ElementNames {
-            prefixes: &["Aer", "Zeph", "Gust", "Breez", "Storm", "Temp", "Cycl", "Whirl"],
-            suffixes: &["a", "is", "us", "on", "an", "ix"],
-        },
-    ),
-    (
-        DragonElement::Lightning,
-        ElementNames {
-            prefixes: &["Volt", "Thund", "Bolt", "Spark", "Flash", "Strik", "Shock", "Electr"],
-            suffixes: &["a", "is", "us", "on", "ix", "ax"],
-        },
-    ),
-    (
-        DragonElement::Ice,
-        ElementNames {
-            prefixes: &["Cryo", "Frost", "Glac", "Ic", "Frig", "Chill", "Freez", "Cryst"],
-            suffixes: &["a", "is", "us", "on", "an", "ix"],
-        },
-    ),
-];
+impl NameMethod {
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "syllable" => Some(NameMethod::Syllable),
+            "markov" => Some(NameMethod::Markov),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NameMethod::Syllable => "syllable",
+            NameMethod::Markov => "markov",
+        }
+    }
+}
+
+/// Pick one word from a list, or "" if it is empty
+fn pick<'a>(words: &'a [String], rng: &mut impl Rng) -> &'a str {
+    words.choose(rng).map(String::as_str).unwrap_or_default()
+}
 
 #[wasm_bindgen]
 pub fn generate_dragon_name(element_str: Option<String>) -> String {
-    let element = element_str.and_then(|s| element_from_str(&s));
-    generate_dragon_name_internal(element)
+    generate_syllable_name(DEFAULT_NAME_STYLE, element_str.as_deref())
 }
 
-fn generate_dragon_name_internal(element: Option<DragonElement>) -> String {
-    let mut rng = rand::thread_rng();
-    
-    if let Some(element) = element {
-        if rng.gen_bool(0.7) {
-            return generate_element_name(element);
+/// Generate a name with a Markov model trained on the element's seed names
+/// Without an element, the model learns from every element's seed names
+#[wasm_bindgen]
+pub fn generate_markov_name(element_str: Option<String>) -> String {
+    generate_name(NameMethod::Markov, DEFAULT_NAME_STYLE, element_str.as_deref())
+}
+
+/// Generate a dragon name with the chosen method and name style
+/// Markov names fall back to syllables for elements without seed names
+pub(crate) fn generate_name(method: NameMethod, style: &str, element: Option<&str>) -> String {
+    if method == NameMethod::Markov {
+        let corpus = match element {
+            Some(element) => element::name_corpus(&[element]),
+            None => element::name_corpus(&element::element_names().iter().map(String::as_str).collect::<Vec<_>>()),
+        };
+        if let Some(name) = markov::generate_name(&corpus, &mut rand::thread_rng()) {
+            return name;
         }
     }
+    generate_syllable_name(style, element)
+}
 
-    let prefix = NAME_SYLLABLES[rng.gen_range(0..NAME_SYLLABLES.len())];
-    let middle = if rng.gen_bool(0.5) {
-        NAME_MIDDLES[rng.gen_range(0..NAME_MIDDLES.len())]
-    } else {
-        ""
-    };
-    let suffix = NAME_SUFFIXES[rng.gen_range(0..NAME_SUFFIXES.len())];
+fn generate_syllable_name(style: &str, element: Option<&str>) -> String {
+    name_style::with_style(style, |style| {
+        let mut rng = rand::thread_rng();
+
+        if let Some(element) = element {
+            if rng.gen_bool(0.7) {
+                if let Some(name) = generate_element_name(style, element, &mut rng) {
+                    return name;
+                }
+            }
+        }
 
-    format!("{}{}{}", prefix, middle, suffix)
+        let prefix = pick(&style.syllables, &mut rng);
+        let middle = if rng.gen_bool(0.5) { pick(&style.middles, &mut rng) } else { "" };
+        let suffix = pick(&style.suffixes, &mut rng);
+        format!("{}{}{}", prefix, middle, suffix)
+    })
 }
 
-fn generate_element_name(element: DragonElement) -> String {
+/// Join one of the element's prefixes to one of its suffixes
+/// Returns None if the style and element have no syllables for it
+fn generate_element_name(style: &NameStyle, element: &str, rng: &mut impl Rng) -> Option<String> {
+    let (prefixes, suffixes) = style.element_syllables(element);
+    if prefixes.is_empty() || suffixes.is_empty() {
+        return None;
+    }
+    Some(format!("{}{}", pick(&prefixes, rng), pick(&suffixes, rng)))
+}
+
+/// Generate a name for a hybrid dragon, mixing the prefixes of one element with the suffixes of the other
+/// Markov names learn from both elements' seed names at once
+pub(crate) fn generate_hybrid_name(method: NameMethod, style: &str, element: &str, secondary_element: &str) -> String {
     let mut rng = rand::thread_rng();
-    
-    for (elem, names) in ELEMENT_NAMES {
-        if *elem == element {
-            let prefix = names.prefixes[rng.gen_range(0..names.prefixes.len())];
-            let suffix = names.suffixes[rng.gen_range(0..names.suffixes.len())];
-            return format!("{}{}", prefix, suffix);
+    if method == NameMethod::Markov {
+        let corpus = element::name_corpus(&[element, secondary_element]);
+        if let Some(name) = markov::generate_name(&corpus, &mut rng) {
+            return name;
         }
     }
-    
-    generate_dragon_name_internal(None)
+
+    let (prefix_element, suffix_element) = if rng.gen_bool(0.5) {
+        (element, secondary_element)
+    } else {
+        (secondary_element, element)
+    };
+
+    let name = name_style::with_style(style, |style| {
+        let (prefixes, _) = style.element_syllables(prefix_element);
+        let (_, suffixes) = style.element_syllables(suffix_element);
+        if prefixes.is_empty() || suffixes.is_empty() {
+            return None;
+        }
+        Some(format!("{}{}", pick(&prefixes, &mut rng), pick(&suffixes, &mut rng)))
+    });
+    name.unwrap_or_else(|| generate_syllable_name(style, Some(element)))
 }
 
 #[wasm_bindgen]
 pub fn generate_multiple_names(count: usize, element_str: Option<String>) -> Vec<String> {
-    let element = element_str.and_then(|s| element_from_str(&s));
     let mut names = std::collections::HashSet::new();
     while names.len() < count {
-        names.insert(generate_dragon_name_internal(element));
+        names.insert(generate_syllable_name(DEFAULT_NAME_STYLE, element_str.as_deref()));
     }
     names.into_iter().collect()
 }
 
-const CLAN_ADJECTIVES: &[&str] = &[
-    "Fireborn", "Storm", "Ancient", "Eternal", "Shadow", "Crystal", "Thunder",
-    "Dragon", "Mystic", "Sacred", "Frozen", "Blazing", "Golden", "Silver",
-    "Iron", "Steel", "Frost", "Flame", "Wind", "Earth", "Lightning", "Ice",
-    "Noble", "Royal", "Wild", "Fierce", "Mighty", "Legendary", "Divine",
-    "Dark", "Bright", "Savage", "Wise", "Bold", "Swift", "Strong"
-];
-
-const CLAN_NOUNS: &[&str] = &[
-    "Clan", "Order", "Brotherhood", "Sisterhood", "Guild", "Circle", "Council",
-    "Alliance", "Legion", "Guard", "Keep", "Tower", "Sanctuary", "Haven",
-    "Stronghold", "Fortress", "Realm", "Domain", "Tribe", "Nation", "Empire",
-    "Dynasty", "House", "Bloodline", "Lineage", "Horde", "Flight", "Wing"
-];
-
 #[wasm_bindgen]
 pub fn generate_clan_name() -> String {
-    let mut rng = rand::thread_rng();
-    let adjective = CLAN_ADJECTIVES[rng.gen_range(0..CLAN_ADJECTIVES.len())];
-    let noun = CLAN_NOUNS[rng.gen_range(0..CLAN_NOUNS.len())];
-    format!("The {} {}", adjective, noun)
+    random_clan_name(DEFAULT_NAME_STYLE)
 }
 
+/// A clan name from the style's general adjectives and nouns, e.g. "The Iron Hall"
+pub(crate) fn random_clan_name(style: &str) -> String {
+    name_style::with_style(style, |style| {
+        let mut rng = rand::thread_rng();
+        format!("The {} {}", pick(&style.clan_adjectives, &mut rng), pick(&style.clan_nouns, &mut rng))
+    })
+}
@@ -0,0 +1,195 @@
+use crate::element;
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Built-in name style packs, embedded at compile time
+const DEFAULT_NAME_STYLES: &str = include_str!("../data/name_styles.json");
+
+/// Style used when none is chosen, and in place of styles that are no longer registered
+pub const DEFAULT_NAME_STYLE: &str = "classic";
+
+/// Syllables a style uses for dragons of one element
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ElementSyllables {
+    #[serde(default)]
+    pub prefixes: Vec<String>,
+    #[serde(default)]
+    pub suffixes: Vec<String>,
+}
+
+/// A named pack of word lists that gives dragon and clan names one flavour, e.g. "norse"
+#[derive(Debug, Clone, Deserialize)]
+pub struct NameStyle {
+    pub name: String,
+    /// Opening syllables of names not drawn from an element
+    pub syllables: Vec<String>,
+    /// Optional middle syllables
+    #[serde(default)]
+    pub middles: Vec<String>,
+    pub suffixes: Vec<String>,
+    /// Syllables for dragons of each element; elements not listed use their own name syllables
+    #[serde(default)]
+    pub elements: HashMap<String, ElementSyllables>,
+    pub clan_adjectives: Vec<String>,
+    pub clan_nouns: Vec<String>,
+    /// Clan adjectives when an element dominates; elements not listed use their own clan adjectives
+    #[serde(default)]
+    pub element_clan_adjectives: HashMap<String, Vec<String>>,
+    /// Clan nouns when a value is held highest
+    #[serde(default)]
+    pub value_clan_nouns: HashMap<String, Vec<String>>,
+}
+
+impl NameStyle {
+    /// Name prefixes and suffixes for an element: the style's own, or else the element's
+    pub fn element_syllables(&self, element: &str) -> (Vec<String>, Vec<String>) {
+        match self.elements.get(element) {
+            Some(syllables) if !syllables.prefixes.is_empty() && !syllables.suffixes.is_empty() => {
+                (syllables.prefixes.clone(), syllables.suffixes.clone())
+            }
+            _ => element::name_syllables(element).unwrap_or_default(),
+        }
+    }
+
+    /// Clan adjectives for an element: the style's own, or else the element's
+    pub fn clan_adjectives_for(&self, element: &str) -> Vec<String> {
+        match self.element_clan_adjectives.get(element) {
+            Some(adjectives) if !adjectives.is_empty() => adjectives.clone(),
+            _ => element::clan_adjectives(element),
+        }
+    }
+
+    /// Clan nouns for a value, empty if the style has none for it
+    pub fn clan_nouns_for(&self, value: &str) -> &[String] {
+        self.value_clan_nouns.get(value).map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct NameStyleFile {
+    styles: Vec<NameStyle>,
+}
+
+/// All name styles, in definition order
+#[derive(Debug, Clone)]
+pub struct NameStyleRegistry {
+    styles: Vec<NameStyle>,
+}
+
+impl NameStyleRegistry {
+    /// Parse name styles from JSON of the form {"styles": [...]}
+    pub fn from_json(json: &str) -> Result<Vec<NameStyle>, String> {
+        let file: NameStyleFile = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        for style in &file.styles {
+            if style.name.trim().is_empty() {
+                return Err("name style must have a name".to_string());
+            }
+            let required = [
+                ("syllables", &style.syllables),
+                ("suffixes", &style.suffixes),
+                ("clan_adjectives", &style.clan_adjectives),
+                ("clan_nouns", &style.clan_nouns),
+            ];
+            if let Some((list, _)) = required.iter().find(|(_, words)| words.is_empty()) {
+                return Err(format!("name style '{}' has no {}", style.name, list));
+            }
+        }
+        Ok(file.styles)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&NameStyle> {
+        self.styles.iter().find(|s| s.name == name)
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.styles.iter().map(|s| s.name.clone()).collect()
+    }
+
+    /// Add new styles, replacing any existing style with the same name
+    pub fn register(&mut self, styles: Vec<NameStyle>) {
+        for style in styles {
+            match self.styles.iter_mut().find(|s| s.name == style.name) {
+                Some(existing) => *existing = style,
+                None => self.styles.push(style),
+            }
+        }
+    }
+}
+
+impl Default for NameStyleRegistry {
+    fn default() -> Self {
+        NameStyleRegistry {
+            styles: NameStyleRegistry::from_json(DEFAULT_NAME_STYLES).expect("built-in name styles are valid"),
+        }
+    }
+}
+
+// Global name style registry
+// For WASM (single-threaded), we use a simple thread_local with RefCell
+thread_local! {
+    static NAME_STYLES: RefCell<NameStyleRegistry> = RefCell::new(NameStyleRegistry::default());
+}
+
+/// Read from a name style, using the default style if it is not registered
+pub fn with_style<R>(name: &str, f: impl FnOnce(&NameStyle) -> R) -> R {
+    NAME_STYLES.with(|registry| {
+        let registry = registry.borrow();
+        let style = registry
+            .get(name)
+            .or_else(|| registry.get(DEFAULT_NAME_STYLE))
+            .expect("the default name style is always registered");
+        f(style)
+    })
+}
+
+pub fn is_known(name: &str) -> bool {
+    NAME_STYLES.with(|registry| registry.borrow().get(name).is_some())
+}
+
+/// Names of all registered styles, in definition order
+pub fn style_names() -> Vec<String> {
+    NAME_STYLES.with(|registry| registry.borrow().names())
+}
+
+/// Register name styles from JSON, replacing styles with the same name
+/// Returns the number of styles loaded
+pub fn load_name_styles(json: &str) -> Result<usize, String> {
+    let styles = NameStyleRegistry::from_json(json)?;
+    let count = styles.len();
+    NAME_STYLES.with(|registry| registry.borrow_mut().register(styles));
+    Ok(count)
+}
+
+/// Restore the built-in name styles
+pub fn reset_name_styles() {
+    NAME_STYLES.with(|registry| *registry.borrow_mut() = NameStyleRegistry::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_style_falls_back_to_element_syllables() {
+        let mut registry = NameStyleRegistry::default();
+        assert_eq!(registry.names(), ["classic", "norse", "draconic-harsh", "elvish-soft"]);
+
+        let styles = NameStyleRegistry::from_json(
+            r#"{"styles": [{"name": "sylvan", "syllables": ["Fen"], "suffixes": ["wick"],
+                "elements": {"Earth": {"prefixes": ["Moss"], "suffixes": ["root"]}},
+                "clan_adjectives": ["Green"], "clan_nouns": ["Thicket"]}]}"#,
+        )
+        .unwrap();
+        registry.register(styles);
+
+        let sylvan = registry.get("sylvan").unwrap();
+        assert_eq!(sylvan.element_syllables("Earth"), (vec!["Moss".to_string()], vec!["root".to_string()]));
+        assert_eq!(sylvan.element_syllables("Fire"), element::name_syllables("Fire").unwrap());
+        assert_eq!(sylvan.clan_adjectives_for("Ice"), element::clan_adjectives("Ice"));
+        assert!(sylvan.clan_nouns_for("wisdom").is_empty());
+
+        let missing_nouns = r#"{"styles": [{"name": "bare", "syllables": ["A"], "suffixes": ["b"], "clan_adjectives": ["C"], "clan_nouns": []}]}"#;
+        assert_eq!(NameStyleRegistry::from_json(missing_nouns).unwrap_err(), "name style 'bare' has no clan_nouns");
+    }
+}
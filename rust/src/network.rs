@@ -0,0 +1,276 @@
+use crate::chronicle::FRIENDSHIP_OPINION;
+
+/// Differences smaller than this are treated as equal when comparing path lengths and scores
+const EPSILON: f64 = 1e-9;
+
+/// Most rounds of power iteration for eigenvector centrality
+const MAX_EIGENVECTOR_ITERATIONS: usize = 200;
+
+/// Most passes over the dragons when grouping them into communities
+const MAX_COMMUNITY_PASSES: usize = 100;
+
+/// The clan's positive opinions as a weighted graph
+/// A dragon's liking of another is its opinion / 100 if positive, and 0 otherwise
+#[derive(Debug, Clone)]
+pub struct SocialGraph {
+    size: usize,
+    liking: Vec<f64>,
+}
+
+impl SocialGraph {
+    /// Build the graph from a row-major size×size opinion matrix
+    pub fn from_opinions(size: usize, opinions: &[i32]) -> Self {
+        let liking = opinions.iter().map(|&opinion| opinion.max(0) as f64 / 100.0).collect();
+        SocialGraph { size, liking }
+    }
+
+    fn liking(&self, from: usize, to: usize) -> f64 {
+        self.liking[from * self.size + to]
+    }
+
+    /// Strength of the tie between two dragons, ignoring direction: the average of how much
+    /// each likes the other
+    fn tie(&self, a: usize, b: usize) -> f64 {
+        if a == b {
+            0.0
+        } else {
+            (self.liking(a, b) + self.liking(b, a)) / 2.0
+        }
+    }
+
+    /// Whether both dragons like each other enough to be friends
+    fn are_friends(&self, a: usize, b: usize) -> bool {
+        let friendship = FRIENDSHIP_OPINION as f64 / 100.0;
+        a != b && self.liking(a, b) >= friendship && self.liking(b, a) >= friendship
+    }
+
+    /// How well liked each dragon is: the others' liking of it, averaged over the others (0-1)
+    pub fn in_degree(&self) -> Vec<f64> {
+        self.degree(|graph, i, j| graph.liking(j, i))
+    }
+
+    /// How much each dragon likes the others, averaged over the others (0-1)
+    pub fn out_degree(&self) -> Vec<f64> {
+        self.degree(|graph, i, j| graph.liking(i, j))
+    }
+
+    fn degree(&self, weight: impl Fn(&Self, usize, usize) -> f64) -> Vec<f64> {
+        if self.size < 2 {
+            return vec![0.0; self.size];
+        }
+        (0..self.size)
+            .map(|i| (0..self.size).filter(|&j| j != i).map(|j| weight(self, i, j)).sum::<f64>() / (self.size - 1) as f64)
+            .collect()
+    }
+
+    /// How often each dragon lies on the strongest chains of ties between two others (0-1)
+    /// A chain's length is the sum of 1 / tie strength over its links, so strong ties are short
+    pub fn betweenness(&self) -> Vec<f64> {
+        let n = self.size;
+        let mut centrality = vec![0.0; n];
+        if n < 3 {
+            return centrality;
+        }
+
+        // Brandes' algorithm, finding shortest paths from each source with Dijkstra's
+        for source in 0..n {
+            let mut distance = vec![f64::INFINITY; n];
+            let mut paths = vec![0.0; n];
+            let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); n];
+            let mut settled = vec![false; n];
+            let mut order = Vec::with_capacity(n);
+            distance[source] = 0.0;
+            paths[source] = 1.0;
+
+            while let Some(current) = (0..n)
+                .filter(|&v| !settled[v] && distance[v].is_finite())
+                .min_by(|&a, &b| distance[a].total_cmp(&distance[b]))
+            {
+                settled[current] = true;
+                order.push(current);
+                for next in (0..n).filter(|&v| !settled[v]) {
+                    let tie = self.tie(current, next);
+                    if tie <= 0.0 {
+                        continue;
+                    }
+                    let through = distance[current] + 1.0 / tie;
+                    if through < distance[next] - EPSILON {
+                        distance[next] = through;
+                        paths[next] = paths[current];
+                        predecessors[next] = vec![current];
+                    } else if (through - distance[next]).abs() <= EPSILON {
+                        paths[next] += paths[current];
+                        predecessors[next].push(current);
+                    }
+                }
+            }
+
+            let mut dependency = vec![0.0; n];
+            for &v in order.iter().rev() {
+                for &u in &predecessors[v] {
+                    dependency[u] += paths[u] / paths[v] * (1.0 + dependency[v]);
+                }
+                if v != source {
+                    centrality[v] += dependency[v];
+                }
+            }
+        }
+
+        // Each pair was counted from both ends; scale by the number of pairs of other dragons
+        let pairs = ((n - 1) * (n - 2)) as f64;
+        centrality.iter().map(|c| c / pairs).collect()
+    }
+
+    /// How well tied each dragon is to other well-tied dragons, scaled so the highest is 1
+    /// Dragons with no ties score 0
+    pub fn eigenvector(&self) -> Vec<f64> {
+        let n = self.size;
+        let mut scores = vec![1.0; n];
+        for _ in 0..MAX_EIGENVECTOR_ITERATIONS {
+            // Adding each score to itself keeps the iteration from oscillating
+            let mut next: Vec<f64> = (0..n)
+                .map(|i| scores[i] + (0..n).map(|j| self.tie(i, j) * scores[j]).sum::<f64>())
+                .collect();
+            let max = next.iter().cloned().fold(0.0, f64::max);
+            next.iter_mut().for_each(|score| *score /= max);
+            let settled = next.iter().zip(&scores).all(|(a, b)| (a - b).abs() < EPSILON);
+            scores = next;
+            if settled {
+                break;
+            }
+        }
+
+        // Dragons without ties only keep the score they added to themselves
+        scores
+            .iter()
+            .zip(self.isolated())
+            .map(|(&score, isolated)| if isolated { 0.0 } else { score })
+            .collect()
+    }
+
+    /// Whether each dragon has no positive tie with anyone, in either direction
+    pub fn isolated(&self) -> Vec<bool> {
+        (0..self.size)
+            .map(|i| (0..self.size).all(|j| self.tie(i, j) <= 0.0))
+            .collect()
+    }
+
+    /// Groups of three or more dragons who are all friends with each other, largest first
+    /// Only maximal groups are listed, so no group is part of a larger one
+    pub fn friend_cliques(&self) -> Vec<Vec<usize>> {
+        let mut cliques = Vec::new();
+        self.extend_cliques(Vec::new(), (0..self.size).collect(), Vec::new(), &mut cliques);
+        cliques.retain(|clique| clique.len() >= 3);
+        cliques.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        cliques
+    }
+
+    /// Bron–Kerbosch: grow clique from candidates, skipping anything already reported via excluded
+    fn extend_cliques(&self, clique: Vec<usize>, mut candidates: Vec<usize>, mut excluded: Vec<usize>, cliques: &mut Vec<Vec<usize>>) {
+        if candidates.is_empty() && excluded.is_empty() {
+            cliques.push(clique);
+            return;
+        }
+        while let Some(dragon) = candidates.pop() {
+            let mut grown = clique.clone();
+            grown.push(dragon);
+            grown.sort_unstable();
+            let friends_only = |list: &[usize]| list.iter().copied().filter(|&other| self.are_friends(dragon, other)).collect();
+            self.extend_cliques(grown, friends_only(&candidates), friends_only(&excluded), cliques);
+            excluded.push(dragon);
+        }
+    }
+
+    /// Community of each dragon, numbered from 0 in order of first appearance
+    /// Dragons are moved between communities while that raises the modularity of the split
+    /// (the local moving phase of the Louvain method); dragons with no ties stay on their own
+    pub fn communities(&self) -> Vec<usize> {
+        let n = self.size;
+        let mut community: Vec<usize> = (0..n).collect();
+        let strength: Vec<f64> = (0..n).map(|i| (0..n).map(|j| self.tie(i, j)).sum()).collect();
+        let total: f64 = strength.iter().sum();
+
+        if total > 0.0 {
+            let mut community_strength = strength.clone();
+            for _ in 0..MAX_COMMUNITY_PASSES {
+                let mut moved = false;
+                for dragon in 0..n {
+                    let current = community[dragon];
+                    community_strength[current] -= strength[dragon];
+
+                    // Gain in modularity from joining a community, up to a constant factor
+                    let gain = |target: usize| {
+                        let ties: f64 = (0..n).filter(|&j| j != dragon && community[j] == target).map(|j| self.tie(dragon, j)).sum();
+                        ties - community_strength[target] * strength[dragon] / total
+                    };
+                    let mut best = (current, gain(current));
+                    for neighbour in (0..n).filter(|&j| self.tie(dragon, j) > 0.0) {
+                        let target = community[neighbour];
+                        let target_gain = gain(target);
+                        if target != best.0 && target_gain > best.1 + EPSILON {
+                            best = (target, target_gain);
+                        }
+                    }
+
+                    community[dragon] = best.0;
+                    community_strength[best.0] += strength[dragon];
+                    moved |= best.0 != current;
+                }
+                if !moved {
+                    break;
+                }
+            }
+        }
+
+        let mut labels: Vec<usize> = Vec::new();
+        community
+            .iter()
+            .map(|c| match labels.iter().position(|label| label == c) {
+                Some(index) => index,
+                None => {
+                    labels.push(*c);
+                    labels.len() - 1
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_two_friend_groups_joined_by_a_bridge() {
+        // Dragons 0-2 and 3-5 are close friends, 2 and 3 are on good terms, and 6 is a loner
+        let size = 7;
+        let mut opinions = vec![0; size * size];
+        let mut set = |a: usize, b: usize, opinion: i32| {
+            opinions[a * size + b] = opinion;
+            opinions[b * size + a] = opinion;
+        };
+        for group in [[0, 1, 2], [3, 4, 5]] {
+            set(group[0], group[1], 80);
+            set(group[0], group[2], 80);
+            set(group[1], group[2], 80);
+        }
+        set(2, 3, 30);
+        set(6, 0, -40);
+        let graph = SocialGraph::from_opinions(size, &opinions);
+
+        assert_eq!(graph.friend_cliques(), vec![vec![0, 1, 2], vec![3, 4, 5]]);
+        assert_eq!(graph.communities(), vec![0, 0, 0, 1, 1, 1, 2]);
+        assert_eq!(graph.isolated(), vec![false, false, false, false, false, false, true]);
+
+        let betweenness = graph.betweenness();
+        assert!(betweenness[2] > betweenness[0] && betweenness[3] > betweenness[4]);
+        assert!((betweenness[2] - betweenness[3]).abs() < EPSILON);
+        assert_eq!(betweenness[6], 0.0);
+
+        let eigenvector = graph.eigenvector();
+        assert!((eigenvector[2] - 1.0).abs() < 1e-6 && eigenvector[2] > eigenvector[0]);
+        assert_eq!(eigenvector[6], 0.0);
+        assert_eq!(graph.in_degree()[6], 0.0);
+        assert!((graph.in_degree()[2] - 190.0 / 600.0).abs() < EPSILON);
+    }
+}
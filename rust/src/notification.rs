@@ -12,6 +12,13 @@ pub enum EventType {
     InteractionSimulated,
     ClanReset,
     Error,
+    BondFormed,
+    ClanRenamed,
+    FactionFormed,
+    FactionSplit,
+    FactionMerged,
+    FactionDissolved,
+    AbilityUsed,
 }
 
 /// Generic notification service that can emit events to JavaScript
@@ -32,7 +39,7 @@ impl NotificationService {
     /// The callback will receive a JsValue representing the event data
     pub fn subscribe(&self, event_type: EventType, callback: JsValue) {
         let mut callbacks = self.callbacks.borrow_mut();
-        let callbacks_for_type = callbacks.entry(event_type).or_insert_with(Vec::new);
+        let callbacks_for_type = callbacks.entry(event_type).or_default();
         callbacks_for_type.push(callback);
     }
 
@@ -42,9 +49,9 @@ impl NotificationService {
         if let Some(callbacks_for_type) = callbacks.get_mut(&event_type) {
             // For WASM, we'll use a simple approach: compare by converting to string
             // This isn't perfect but works for our use case
-            let callback_str = format!("{:p}", callback.as_ref() as *const _);
+            let callback_str = format!("{:p}", callback as *const _);
             callbacks_for_type.retain(|cb| {
-                let cb_str = format!("{:p}", cb.as_ref() as *const _);
+                let cb_str = format!("{:p}", cb as *const _);
                 cb_str != callback_str
             });
         }
@@ -67,7 +74,7 @@ impl NotificationService {
 // Global notification service instance
 // For WASM (single-threaded), we use a simple static with RefCell
 thread_local! {
-    pub static NOTIFICATION_SERVICE: RefCell<Option<NotificationService>> = RefCell::new(None);
+    pub static NOTIFICATION_SERVICE: RefCell<Option<NotificationService>> = const { RefCell::new(None) };
 }
 
 pub fn get_notification_service() -> &'static NotificationService {
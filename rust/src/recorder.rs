@@ -0,0 +1,247 @@
+use std::collections::{HashMap, VecDeque};
+
+/// Samples kept in each series unless configured otherwise
+pub const DEFAULT_RECORDER_CAPACITY: usize = 500;
+
+/// What the recorder keeps, and how often it samples
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecorderConfig {
+    pub enabled: bool,
+    /// Samples kept in each series before the oldest are dropped
+    pub capacity: usize,
+    /// Ticks of the clan clock between samples
+    pub interval: u32,
+}
+
+impl Default for RecorderConfig {
+    fn default() -> Self {
+        RecorderConfig {
+            enabled: true,
+            capacity: DEFAULT_RECORDER_CAPACITY,
+            interval: 1,
+        }
+    }
+}
+
+/// Buffer of samples that drops the oldest once it is full
+#[derive(Debug, Clone)]
+pub struct RingBuffer<T> {
+    samples: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<T: Clone> RingBuffer<T> {
+    pub fn new(capacity: usize) -> Self {
+        RingBuffer {
+            samples: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    pub fn push(&mut self, sample: T) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    /// Change how many samples the buffer holds, dropping the oldest if it shrinks
+    pub fn set_capacity(&mut self, capacity: usize) {
+        while self.samples.len() > capacity {
+            self.samples.pop_front();
+        }
+        self.capacity = capacity;
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Samples, oldest first
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.samples.iter()
+    }
+
+    /// Samples, oldest first
+    pub fn to_vec(&self) -> Vec<T> {
+        self.samples.iter().cloned().collect()
+    }
+}
+
+/// Clan-wide measures at one moment
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ClanMetrics {
+    pub dragon_count: u32,
+    pub average_opinion: f64,
+    pub polarization: f64,
+    pub reciprocity: f64,
+    pub friendships: u32,
+    pub rivalries: u32,
+    pub mood: f64,
+    pub morale: i32,
+}
+
+/// One dragon's standing at one moment
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DragonMetrics {
+    /// Its average opinion of the other dragons
+    pub opinion_given: f64,
+    /// The other dragons' average opinion of it
+    pub opinion_received: f64,
+}
+
+/// Series of opinions and clan measures over time, sampled as the clan clock ticks
+/// Each sample is stamped with the time in days, e.g. 3.5 for halfway through day 3
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    config: RecorderConfig,
+    ticks: u64,
+    /// Opinion series by (holder, subject) name
+    pairs: HashMap<(String, String), RingBuffer<(f64, i32)>>,
+    dragons: HashMap<String, RingBuffer<(f64, DragonMetrics)>>,
+    clan: RingBuffer<(f64, ClanMetrics)>,
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Recorder::new(RecorderConfig::default())
+    }
+}
+
+impl Recorder {
+    pub fn new(config: RecorderConfig) -> Self {
+        let mut recorder = Recorder {
+            config,
+            ticks: 0,
+            pairs: HashMap::new(),
+            dragons: HashMap::new(),
+            clan: RingBuffer::new(config.capacity),
+        };
+        recorder.configure(config);
+        recorder
+    }
+
+    /// Change the configuration, keeping the most recent samples that still fit
+    /// Capacity and interval are at least 1
+    pub fn configure(&mut self, config: RecorderConfig) {
+        let capacity = config.capacity.max(1);
+        self.config = RecorderConfig {
+            capacity,
+            interval: config.interval.max(1),
+            ..config
+        };
+        self.pairs.values_mut().for_each(|series| series.set_capacity(capacity));
+        self.dragons.values_mut().for_each(|series| series.set_capacity(capacity));
+        self.clan.set_capacity(capacity);
+    }
+
+    /// Count a tick of the clan clock, returning whether a sample is due
+    pub fn tick(&mut self) -> bool {
+        if !self.config.enabled {
+            return false;
+        }
+        self.ticks += 1;
+        self.ticks.is_multiple_of(self.config.interval as u64)
+    }
+
+    /// Record a sample from a row-major names.len()×names.len() opinion matrix and the clan's metrics
+    pub fn record(&mut self, time: f64, names: &[String], opinions: &[i32], clan: ClanMetrics) {
+        let n = names.len();
+        let capacity = self.config.capacity;
+        let mut given = vec![0.0; n];
+        let mut received = vec![0.0; n];
+        for (i, holder) in names.iter().enumerate() {
+            for (j, subject) in names.iter().enumerate().filter(|(j, _)| *j != i) {
+                let opinion = opinions[i * n + j];
+                given[i] += opinion as f64;
+                received[j] += opinion as f64;
+                self.pairs
+                    .entry((holder.clone(), subject.clone()))
+                    .or_insert_with(|| RingBuffer::new(capacity))
+                    .push((time, opinion));
+            }
+        }
+
+        let others = n.saturating_sub(1).max(1) as f64;
+        for (i, name) in names.iter().enumerate() {
+            self.dragons.entry(name.clone()).or_insert_with(|| RingBuffer::new(capacity)).push((
+                time,
+                DragonMetrics {
+                    opinion_given: given[i] / others,
+                    opinion_received: received[i] / others,
+                },
+            ));
+        }
+        self.clan.push((time, clan));
+    }
+
+    /// How one dragon's opinion of another has changed, oldest first
+    pub fn pair_series(&self, holder: &str, subject: &str) -> Vec<(f64, i32)> {
+        self.pairs
+            .get(&(holder.to_string(), subject.to_string()))
+            .map(RingBuffer::to_vec)
+            .unwrap_or_default()
+    }
+
+    pub fn dragon_series(&self, name: &str) -> Vec<(f64, DragonMetrics)> {
+        self.dragons.get(name).map(RingBuffer::to_vec).unwrap_or_default()
+    }
+
+    pub fn clan_series(&self) -> Vec<(f64, ClanMetrics)> {
+        self.clan.to_vec()
+    }
+
+    /// Drop the series of a dragon that has left, and of every pair it was part of
+    pub fn forget(&mut self, name: &str) {
+        self.pairs.retain(|(holder, subject), _| holder != name && subject != name);
+        self.dragons.remove(name);
+    }
+
+    /// Drop every sample, keeping the configuration
+    pub fn clear(&mut self) {
+        *self = Recorder::new(self.config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recorder_samples_every_interval_and_keeps_the_latest() {
+        let mut recorder = Recorder::new(RecorderConfig {
+            enabled: true,
+            capacity: 3,
+            interval: 2,
+        });
+        let names = vec!["Ignis".to_string(), "Aqua".to_string(), "Terra".to_string()];
+
+        for tick in 1..=10 {
+            if recorder.tick() {
+                let opinion = tick * 10;
+                let opinions = [0, opinion, 0, -opinion, 0, 0, 0, 0, 0];
+                recorder.record(tick as f64, &names, &opinions, ClanMetrics::default());
+            }
+        }
+
+        assert_eq!(recorder.pair_series("Ignis", "Aqua"), vec![(6.0, 60), (8.0, 80), (10.0, 100)]);
+        assert!(recorder.pair_series("Ignis", "Nobody").is_empty());
+        let aqua = recorder.dragon_series("Aqua");
+        assert_eq!(aqua.last().unwrap().1, DragonMetrics { opinion_given: -50.0, opinion_received: 50.0 });
+        assert_eq!(recorder.clan_series().len(), 3);
+
+        recorder.configure(RecorderConfig {
+            enabled: false,
+            capacity: 1,
+            interval: 1,
+        });
+        assert!(!recorder.tick());
+        assert_eq!(recorder.pair_series("Ignis", "Aqua"), vec![(10.0, 100)]);
+
+        recorder.forget("Aqua");
+        assert!(recorder.pair_series("Ignis", "Aqua").is_empty());
+        assert!(recorder.pair_series("Aqua", "Terra").is_empty());
+        assert!(recorder.dragon_series("Aqua").is_empty());
+        assert_eq!(recorder.pair_series("Ignis", "Terra").len(), 1);
+    }
+}
@@ -1,7 +1,134 @@
+use crate::bond::BondProgress;
+use crate::communication::{CommunicationTone, CommunicationType};
+use crate::locale;
+
+/// Change to the non-opinion dimensions of a relationship caused by one interaction
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DimensionChange {
+    pub respect: i32,
+    pub trust: i32,
+    pub fear: i32,
+}
+
+impl DimensionChange {
+    /// How the receiver's view of the sender shifts after receiving a communication
+    /// and responding with the given tone
+    pub fn for_receiver(comm_type: CommunicationType, tone: CommunicationTone, intensity: u32) -> Self {
+        let strength = intensity as i32 / 10;
+        let positive = matches!(tone, CommunicationTone::Positive | CommunicationTone::Warm);
+        match comm_type {
+            CommunicationType::Confrontational => match tone {
+                // Rising to a challenge breeds grudging respect, not fear
+                CommunicationTone::Challenging => DimensionChange { respect: 3, trust: -5, fear: 0 },
+                // Being cowed by a confrontation makes the sender intimidating
+                CommunicationTone::Negative => DimensionChange { respect: 1, trust: -5, fear: strength },
+                _ => DimensionChange { respect: 0, trust: -3, fear: strength / 2 },
+            },
+            CommunicationType::ValueAlignment => {
+                if positive {
+                    DimensionChange { respect: 3, trust: 4, fear: 0 }
+                } else {
+                    DimensionChange { respect: -1, trust: -2, fear: 0 }
+                }
+            }
+            CommunicationType::Friendly => DimensionChange { respect: 0, trust: if positive { 3 } else { 1 }, fear: -2 },
+            CommunicationType::Playful => DimensionChange { respect: 0, trust: if positive { 2 } else { 0 }, fear: -1 },
+            CommunicationType::Curious => DimensionChange { respect: if positive { 2 } else { 0 }, trust: 1, fear: 0 },
+            CommunicationType::Shy => DimensionChange { respect: -1, trust: 2, fear: -1 },
+            CommunicationType::Neutral => DimensionChange::default(),
+        }
+    }
+
+    /// How the sender's view of the receiver shifts based on how their communication was received
+    pub fn for_sender(comm_type: CommunicationType, tone: CommunicationTone) -> Self {
+        match tone {
+            CommunicationTone::Positive | CommunicationTone::Warm => DimensionChange { respect: 0, trust: 3, fear: 0 },
+            // Standing your ground earns respect, but makes you harder to trust
+            CommunicationTone::Challenging => DimensionChange { respect: 4, trust: -3, fear: 1 },
+            CommunicationTone::Negative => {
+                if comm_type == CommunicationType::Confrontational {
+                    // The receiver backed down
+                    DimensionChange { respect: -3, trust: -1, fear: 0 }
+                } else {
+                    DimensionChange { respect: 0, trust: -3, fear: 0 }
+                }
+            }
+            CommunicationTone::Neutral => DimensionChange::default(),
+        }
+    }
+}
+
+/// How quickly opinions drift back toward neutral when two dragons stop interacting
+#[derive(Debug, Clone, Copy)]
+pub struct OpinionDecay {
+    /// Days without contact for an opinion to fall halfway to neutral
+    pub half_life_days: f64,
+    /// Half-life multiplier when the dragons share a bond
+    pub bond_factor: f64,
+    /// Half-life multiplier for negative opinions held by impatient dragons
+    pub grudge_factor: f64,
+}
+
+impl OpinionDecay {
+    /// Impatient dragons (patience below this) hold grudges longer
+    pub const GRUDGE_PATIENCE: u32 = 30;
+
+    /// Settings that are finite and not negative; a half-life of 0 turns decay off
+    pub fn new(half_life_days: f64, bond_factor: f64, grudge_factor: f64) -> Result<Self, String> {
+        for (name, setting) in [
+            ("half_life_days", half_life_days),
+            ("bond_factor", bond_factor),
+            ("grudge_factor", grudge_factor),
+        ] {
+            if !setting.is_finite() || setting < 0.0 {
+                return Err(format!("{} must be a finite number of at least 0, got {}", name, setting));
+            }
+        }
+        Ok(OpinionDecay {
+            half_life_days,
+            bond_factor,
+            grudge_factor,
+        })
+    }
+
+    /// Effective half-life for one relationship
+    pub fn half_life(&self, opinion: i32, bonded: bool, patience: u32) -> f64 {
+        let mut half_life = self.half_life_days;
+        if bonded {
+            half_life *= self.bond_factor;
+        }
+        if opinion < 0 && patience < Self::GRUDGE_PATIENCE {
+            half_life *= self.grudge_factor;
+        }
+        half_life
+    }
+}
+
+impl Default for OpinionDecay {
+    fn default() -> Self {
+        OpinionDecay {
+            half_life_days: 30.0,
+            bond_factor: 3.0,
+            grudge_factor: 2.0,
+        }
+    }
+}
+
+/// One dragon's view of another
+/// Opinion captures how much the dragon likes the other (affection),
+/// while respect, trust and fear track the other dimensions of the relationship
 #[derive(Debug, Clone)]
 pub struct Relationship {
     opinion: i32,
+    respect: i32,
+    trust: i32,
+    fear: i32,
     interaction_count: u32,
+    bond_progress: BondProgress,
+    /// Day the opinion was last in contact or decayed, which the next decay is measured from
+    decayed_until: u32,
+    /// Fraction of a point decayed but not yet taken off the opinion, so slow daily decay still adds up
+    decay_carry: f64,
 }
 
 impl Relationship {
@@ -10,7 +137,13 @@ impl Relationship {
     pub fn new() -> Self {
         Relationship {
             opinion: 0,
+            respect: 0,
+            trust: 0,
+            fear: 0,
             interaction_count: 0,
+            bond_progress: BondProgress::default(),
+            decayed_until: 0,
+            decay_carry: 0.0,
         }
     }
 
@@ -29,24 +162,73 @@ impl Relationship {
         self.opinion = ((self.opinion as f64 * current_weight)
             + (interaction_value as f64 * interaction_weight)) as i32;
 
-        self.opinion = self.opinion.max(-100).min(100);
+        self.opinion = self.opinion.clamp(-100, 100);
+    }
+
+    /// Mark that the dragons were in contact on the given day, so no decay is owed for it
+    pub fn record_contact(&mut self, day: u32) {
+        self.decayed_until = day;
+        self.decay_carry = 0.0;
     }
 
+    /// Let the current opinion drift toward neutral for the days since it last decayed or the dragons met
+    pub fn decay(&mut self, today: u32, half_life_days: f64) {
+        let days = today.saturating_sub(self.decayed_until);
+        if days == 0 || half_life_days <= 0.0 {
+            return;
+        }
+        let factor = 0.5f64.powf(days as f64 / half_life_days);
+        let decayed = (self.opinion as f64 + self.decay_carry) * factor;
+        self.opinion = decayed.trunc() as i32;
+        self.decay_carry = decayed.fract();
+        self.decayed_until = today;
+    }
+
+    /// Let go of part of a grudge, moving a negative opinion toward neutral by the given fraction
+    pub fn soften_grudge(&mut self, fraction: f64) {
+        if self.opinion < 0 {
+            self.opinion = (self.opinion as f64 * (1.0 - fraction)) as i32;
+        }
+    }
+
+    /// Apply changes to respect, trust and fear
+    /// Respect and trust range from -100 to 100, fear from 0 to 100
+    pub fn update_dimensions(&mut self, change: DimensionChange) {
+        self.respect = (self.respect + change.respect).clamp(-100, 100);
+        self.trust = (self.trust + change.trust).clamp(-100, 100);
+        self.fear = (self.fear + change.fear).clamp(0, 100);
+    }
+
+    /// Describe the relationship, combining opinion with respect, trust and fear
+    /// e.g. a disliked but respected dragon is a "respected rival"
     pub fn get_relationship_description(&self) -> String {
-        if self.opinion >= 80 {
-            "close friends".to_string()
-        } else if self.opinion >= 50 {
-            "friends".to_string()
-        } else if self.opinion >= 20 {
-            "friendly".to_string()
-        } else if self.opinion >= -20 {
-            "neutral".to_string()
-        } else if self.opinion >= -50 {
-            "distant".to_string()
-        } else if self.opinion >= -80 {
-            "unfriendly".to_string()
+        locale::render(self.description_key(), &[])
+    }
+
+    /// Label of get_relationship_description that doesn't depend on the locale,
+    /// e.g. "respected_rival" or "friends"
+    pub fn description_label(&self) -> &'static str {
+        let key = self.description_key();
+        key.split_once('.').map_or(key, |(_, label)| label)
+    }
+
+    fn description_key(&self) -> &'static str {
+        if self.opinion <= -50 && self.respect >= 30 {
+            "relationship.respected_rival"
+        } else if self.opinion <= -20 && self.fear >= 40 {
+            "relationship.feared_enemy"
+        } else if self.opinion >= 50 && self.trust >= 40 {
+            "relationship.trusted_friend"
+        } else if self.opinion >= 20 && self.trust <= -30 {
+            "relationship.wary_ally"
+        } else if self.opinion >= 20 && self.respect >= 40 {
+            "relationship.admired_friend"
+        } else if self.opinion > -20 && self.fear >= 40 {
+            "relationship.intimidating_presence"
+        } else if self.opinion > -20 && self.respect >= 40 {
+            "relationship.respected_acquaintance"
         } else {
-            "rivals".to_string()
+            opinion_key(self.opinion)
         }
     }
 
@@ -72,8 +254,194 @@ impl Relationship {
         self.opinion
     }
 
+    pub fn respect(&self) -> i32 {
+        self.respect
+    }
+
+    pub fn trust(&self) -> i32 {
+        self.trust
+    }
+
+    pub fn fear(&self) -> i32 {
+        self.fear
+    }
+
     pub fn interaction_count(&self) -> u32 {
         self.interaction_count
     }
+
+    /// Progress this pair has made towards courtship, mentorship and rivalry bonds
+    pub fn bond_progress(&self) -> &BondProgress {
+        &self.bond_progress
+    }
+
+    pub fn bond_progress_mut(&mut self) -> &mut BondProgress {
+        &mut self.bond_progress
+    }
+}
+
+/// Label for an opinion, from "rivals" to "close friends", in the current locale
+pub fn describe_opinion(opinion: i32) -> String {
+    locale::render(opinion_key(opinion), &[])
+}
+
+fn opinion_key(opinion: i32) -> &'static str {
+    if opinion >= 80 {
+        "opinion.close_friends"
+    } else if opinion >= 50 {
+        "opinion.friends"
+    } else if opinion >= 20 {
+        "opinion.friendly"
+    } else if opinion >= -20 {
+        "opinion.neutral"
+    } else if opinion >= -50 {
+        "opinion.distant"
+    } else if opinion >= -80 {
+        "opinion.unfriendly"
+    } else {
+        "opinion.rivals"
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bond::BondType;
+
+    #[test]
+    fn test_opinion_decays_toward_neutral() {
+        let decay = OpinionDecay::default();
+        let mut relationship = Relationship::new();
+        relationship.opinion = -60;
+        relationship.record_contact(0);
+
+        relationship.decay(30, decay.half_life(-60, false, 50));
+        assert_eq!(relationship.opinion(), -30);
+
+        // An impatient dragon's grudge fades at half the speed
+        let mut grudge = Relationship::new();
+        grudge.opinion = -60;
+        grudge.record_contact(0);
+        grudge.decay(30, decay.half_life(-60, false, 10));
+        assert!(grudge.opinion() < relationship.opinion());
+    }
+
+    #[test]
+    fn test_decay_settings_must_be_finite_and_not_negative() {
+        let decay = OpinionDecay::new(10.0, 0.0, 2.0).unwrap();
+        assert_eq!(decay.half_life(50, true, 50), 0.0);
+        assert!(OpinionDecay::new(0.0, 3.0, 2.0).is_ok());
+        assert!(OpinionDecay::new(f64::NAN, 3.0, 2.0).is_err());
+        assert!(OpinionDecay::new(30.0, -1.0, 2.0).is_err());
+        assert!(OpinionDecay::new(30.0, 3.0, f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_daily_decay_compounds_from_the_current_opinion() {
+        let decay = OpinionDecay::default();
+        let mut relationship = Relationship::new();
+        relationship.opinion = 80;
+        relationship.record_contact(0);
+        for day in 1..=30 {
+            relationship.decay(day, decay.half_life(relationship.opinion(), false, 50));
+        }
+        // Thirty daily steps halve it just as one thirty-day step would, give or take rounding
+        assert!((39..=40).contains(&relationship.opinion()));
+
+        // A change without contact is decayed along with the rest, not overwritten
+        relationship.opinion = 20;
+        relationship.decay(60, decay.half_life(20, false, 50));
+        assert_eq!(relationship.opinion(), 10);
+    }
+
+    #[test]
+    fn test_close_bonds_slow_decay() {
+        let decay = OpinionDecay::default();
+        let mut friend = Relationship::new();
+        let mut mate = Relationship::new();
+        let mut rival = Relationship::new();
+        for (relationship, bond) in [
+            (&mut friend, None),
+            (&mut mate, Some(BondType::Mate)),
+            (&mut rival, Some(BondType::SwornRival)),
+        ] {
+            relationship.opinion = 60;
+            relationship.record_contact(0);
+            let slowed = bond.is_some_and(|bond| bond.slows_decay());
+            relationship.decay(30, decay.half_life(60, slowed, 50));
+        }
+        assert_eq!(friend.opinion(), 30);
+        assert!(mate.opinion() > friend.opinion());
+        assert_eq!(rival.opinion(), friend.opinion());
+        assert!([BondType::Mate, BondType::Sibling, BondType::Mentor, BondType::Apprentice]
+            .iter()
+            .all(BondType::slows_decay));
+    }
+
+    #[test]
+    fn test_dimensions_and_descriptions() {
+        use CommunicationTone::{Challenging, Negative, Neutral, Positive, Warm};
+        use CommunicationType::{Confrontational, Curious, Friendly, Shy, ValueAlignment};
+
+        let change = |respect, trust, fear| DimensionChange { respect, trust, fear };
+        let receiver_cases = [
+            (Confrontational, Challenging, 60, change(3, -5, 0)),
+            (Confrontational, Negative, 60, change(1, -5, 6)),
+            (Confrontational, Neutral, 60, change(0, -3, 3)),
+            (ValueAlignment, Warm, 60, change(3, 4, 0)),
+            (ValueAlignment, Negative, 60, change(-1, -2, 0)),
+            (Friendly, Positive, 60, change(0, 3, -2)),
+            (Friendly, Neutral, 60, change(0, 1, -2)),
+            (Curious, Positive, 60, change(2, 1, 0)),
+            (Shy, Neutral, 60, change(-1, 2, -1)),
+            (CommunicationType::Neutral, Warm, 60, DimensionChange::default()),
+        ];
+        for (comm_type, tone, intensity, expected) in receiver_cases {
+            let actual = DimensionChange::for_receiver(comm_type, tone, intensity);
+            assert_eq!(actual, expected, "receiver of {:?} answering {:?}", comm_type, tone);
+        }
+
+        let sender_cases = [
+            (Friendly, Warm, change(0, 3, 0)),
+            (Confrontational, Challenging, change(4, -3, 1)),
+            (Confrontational, Negative, change(-3, -1, 0)),
+            (Friendly, Negative, change(0, -3, 0)),
+            (Friendly, Neutral, DimensionChange::default()),
+        ];
+        for (comm_type, tone, expected) in sender_cases {
+            let actual = DimensionChange::for_sender(comm_type, tone);
+            assert_eq!(actual, expected, "sender of {:?} answered with {:?}", comm_type, tone);
+        }
+
+        // (opinion, respect, trust, fear) -> label
+        let description_cases = [
+            ((-60, 30, 0, 0), "respected_rival"),
+            ((-30, 0, 0, 40), "feared_enemy"),
+            ((60, 0, 40, 0), "trusted_friend"),
+            ((30, 0, -30, 0), "wary_ally"),
+            ((30, 40, 0, 0), "admired_friend"),
+            ((0, 0, 0, 40), "intimidating_presence"),
+            ((0, 40, 0, 0), "respected_acquaintance"),
+            ((90, 0, 0, 0), "close_friends"),
+            ((60, 0, 0, 0), "friends"),
+            ((30, 0, 0, 0), "friendly"),
+            ((0, 0, 0, 0), "neutral"),
+            ((-30, 0, 0, 0), "distant"),
+            ((-60, 0, 0, 0), "unfriendly"),
+            ((-90, 0, 0, 0), "rivals"),
+        ];
+        for ((opinion, respect, trust, fear), label) in description_cases {
+            let mut relationship = Relationship::new();
+            relationship.opinion = opinion;
+            relationship.update_dimensions(change(respect, trust, fear));
+            assert_eq!(relationship.description_label(), label, "{:?}", (opinion, respect, trust, fear));
+        }
+
+        // Respect and trust stay within -100..=100, fear within 0..=100
+        let mut relationship = Relationship::new();
+        relationship.update_dimensions(change(150, -150, -20));
+        assert_eq!((relationship.respect(), relationship.trust(), relationship.fear()), (100, -100, 0));
+        relationship.update_dimensions(change(-250, 250, 150));
+        assert_eq!((relationship.respect(), relationship.trust(), relationship.fear()), (-100, 100, 100));
+    }
+}
@@ -0,0 +1,155 @@
+use crate::chronicle::FRIENDSHIP_OPINION;
+use crate::communication::{CommunicationTone, CommunicationType};
+use std::collections::{BTreeMap, HashMap};
+
+/// Opinion two dragons must both hold of each other, or lower, to count as rivals
+pub const RIVALRY_OPINION: i32 = -50;
+
+/// Opinions at least this far from neutral, either way, count towards polarization
+pub const POLARIZED_OPINION: i32 = 50;
+
+/// How well the clan's dragons get on, measured over every relationship that exists
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Cohesion {
+    /// Mean opinion (-100 to 100)
+    pub average_opinion: f64,
+    pub opinion_variance: f64,
+    /// Share of opinions that are strongly for or against (0-1)
+    pub polarization: f64,
+    /// Pairs who think of each other as friends
+    pub friendships: u32,
+    /// Pairs who think of each other as rivals
+    pub rivalries: u32,
+    /// Share of positive opinions that are returned (0-1)
+    pub reciprocity: f64,
+}
+
+impl Cohesion {
+    /// Measure cohesion from opinions by (holder, subject) index
+    pub fn measure(opinions: &HashMap<(usize, usize), i32>) -> Self {
+        if opinions.is_empty() {
+            return Cohesion::default();
+        }
+        let count = opinions.len() as f64;
+        let average_opinion = opinions.values().map(|&o| o as f64).sum::<f64>() / count;
+        let opinion_variance = opinions.values().map(|&o| (o as f64 - average_opinion).powi(2)).sum::<f64>() / count;
+        let polarized = opinions.values().filter(|o| o.abs() >= POLARIZED_OPINION).count();
+
+        let returned = |&(holder, subject): &(usize, usize)| opinions.get(&(subject, holder)).copied();
+        let mut friendships = 0;
+        let mut rivalries = 0;
+        for (pair, &opinion) in opinions.iter().filter(|((holder, subject), _)| holder < subject) {
+            match returned(pair) {
+                Some(other) if opinion >= FRIENDSHIP_OPINION && other >= FRIENDSHIP_OPINION => friendships += 1,
+                Some(other) if opinion <= RIVALRY_OPINION && other <= RIVALRY_OPINION => rivalries += 1,
+                _ => {}
+            }
+        }
+
+        let positive: Vec<&(usize, usize)> = opinions.iter().filter(|(_, &o)| o > 0).map(|(pair, _)| pair).collect();
+        let reciprocated = positive.iter().filter(|pair| returned(pair).is_some_and(|o| o > 0)).count();
+        let reciprocity = if positive.is_empty() {
+            0.0
+        } else {
+            reciprocated as f64 / positive.len() as f64
+        };
+
+        Cohesion {
+            average_opinion,
+            opinion_variance,
+            polarization: polarized as f64 / count,
+            friendships,
+            rivalries,
+            reciprocity,
+        }
+    }
+}
+
+/// How many communications of each type there have been, and how they were received
+#[derive(Debug, Clone, Default)]
+pub struct CommunicationTally {
+    types: BTreeMap<&'static str, u32>,
+    tones: BTreeMap<&'static str, u32>,
+    /// Responses that were positive or warm, less those that were negative or challenging
+    mood_balance: i64,
+    total: u32,
+}
+
+impl CommunicationTally {
+    /// Count a communication and the tone of the response to it
+    pub fn record(&mut self, comm_type: CommunicationType, response_tone: CommunicationTone) {
+        *self.types.entry(comm_type.as_str()).or_insert(0) += 1;
+        *self.tones.entry(response_tone.as_str()).or_insert(0) += 1;
+        self.mood_balance += match response_tone {
+            CommunicationTone::Positive | CommunicationTone::Warm => 1,
+            CommunicationTone::Negative | CommunicationTone::Challenging => -1,
+            CommunicationTone::Neutral => 0,
+        };
+        self.total += 1;
+    }
+
+    /// Communications by type, sorted by type
+    pub fn types(&self) -> Vec<(String, u32)> {
+        self.types.iter().map(|(t, count)| (t.to_string(), *count)).collect()
+    }
+
+    /// Communications by the tone of the response, sorted by tone
+    pub fn tones(&self) -> Vec<(String, u32)> {
+        self.tones.iter().map(|(t, count)| (t.to_string(), *count)).collect()
+    }
+
+    /// Balance of well and badly received communications, from -1 (all badly) to 1 (all well)
+    pub fn mood(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.mood_balance as f64 / self.total as f64
+        }
+    }
+}
+
+/// How often each label occurs, sorted by label
+pub fn tally<'a>(labels: impl IntoIterator<Item = &'a str>) -> Vec<(String, u32)> {
+    let mut counts: BTreeMap<&str, u32> = BTreeMap::new();
+    for label in labels {
+        *counts.entry(label).or_insert(0) += 1;
+    }
+    counts.into_iter().map(|(label, count)| (label.to_string(), count)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cohesion_counts_mutual_ties() {
+        let opinions: HashMap<(usize, usize), i32> = [
+            ((0, 1), 60),
+            ((1, 0), 80),
+            ((0, 2), -60),
+            ((2, 0), -70),
+            ((1, 2), 10),
+            ((2, 1), -10),
+        ]
+        .into_iter()
+        .collect();
+        let cohesion = Cohesion::measure(&opinions);
+
+        assert_eq!((cohesion.friendships, cohesion.rivalries), (1, 1));
+        assert!((cohesion.average_opinion - 10.0 / 6.0).abs() < 1e-9);
+        assert!((cohesion.polarization - 4.0 / 6.0).abs() < 1e-9);
+        // 1 likes 2 but 2 doesn't return it
+        assert!((cohesion.reciprocity - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(Cohesion::measure(&HashMap::new()), Cohesion::default());
+
+        assert_eq!(tally(["Fire", "Ice", "Fire"]), vec![("Fire".to_string(), 2), ("Ice".to_string(), 1)]);
+
+        let mut communications = CommunicationTally::default();
+        communications.record(CommunicationType::Friendly, CommunicationTone::Warm);
+        communications.record(CommunicationType::Friendly, CommunicationTone::Positive);
+        communications.record(CommunicationType::Confrontational, CommunicationTone::Challenging);
+        communications.record(CommunicationType::Curious, CommunicationTone::Neutral);
+        assert_eq!(communications.types()[2], ("friendly".to_string(), 2));
+        assert!((communications.mood() - 0.25).abs() < 1e-9);
+    }
+}
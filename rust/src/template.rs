@@ -0,0 +1,155 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// One variant of a message, with {placeholders} to fill in
+#[derive(Debug, Clone, Deserialize)]
+pub struct Template {
+    pub text: String,
+    /// Relative chance of this variant being picked
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+}
+
+fn default_weight() -> u32 {
+    1
+}
+
+#[derive(Debug, Deserialize)]
+struct TemplateFile {
+    templates: HashMap<String, Vec<Template>>,
+}
+
+/// Message variants by key, e.g. "communication.playful" or "response.value_alignment.shared.honor"
+/// Keys are dotted; a key with no templates falls back to its parent key
+#[derive(Debug, Clone, Default)]
+pub struct TemplateLibrary {
+    templates: HashMap<String, Vec<Template>>,
+}
+
+impl TemplateLibrary {
+    /// Parse templates from JSON of the form {"templates": {"key": [{"text": "...", "weight": 1}]}}
+    pub fn from_json(json: &str) -> Result<HashMap<String, Vec<Template>>, String> {
+        let file: TemplateFile = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        for (key, variants) in &file.templates {
+            if variants.iter().all(|t| t.weight == 0) {
+                return Err(format!("templates for '{}' need at least one variant with a weight", key));
+            }
+        }
+        Ok(file.templates)
+    }
+
+    /// Replace the variants of every key in templates, keeping the other keys
+    pub fn register(&mut self, templates: HashMap<String, Vec<Template>>) {
+        self.templates.extend(templates);
+    }
+
+    /// Variants for a key, falling back to parent keys
+    pub fn variants(&self, key: &str) -> Option<&[Template]> {
+        let mut key = key;
+        loop {
+            if let Some(variants) = self.templates.get(key) {
+                return Some(variants);
+            }
+            key = &key[..key.rfind('.')?];
+        }
+    }
+}
+
+/// Pick a variant at random, weighted by each variant's weight
+pub fn choose(variants: &[Template]) -> Option<&Template> {
+    use rand::Rng;
+    // Summed as u64 so large weights cannot overflow
+    let total: u64 = variants.iter().map(|t| u64::from(t.weight)).sum();
+    if total == 0 {
+        return None;
+    }
+    let mut roll = rand::thread_rng().gen_range(0..total);
+    variants.iter().find(|t| {
+        let weight = u64::from(t.weight);
+        if roll < weight {
+            true
+        } else {
+            roll -= weight;
+            false
+        }
+    })
+}
+
+/// Replace each {name} in text with its value, in a single pass
+/// Values are inserted as they are, so braces inside a value are never expanded; unknown names are left in place
+pub fn fill(text: &str, placeholders: &[(&str, &str)]) -> String {
+    let mut filled = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(open) = rest.find('{') {
+        filled.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let value = after
+            .find('}')
+            .and_then(|close| placeholders.iter().find(|(name, _)| *name == &after[..close]).map(|p| (close, p.1)));
+        match value {
+            Some((close, value)) => {
+                filled.push_str(value);
+                rest = &after[close + 1..];
+            }
+            None => {
+                filled.push('{');
+                rest = after;
+            }
+        }
+    }
+    filled.push_str(rest);
+    filled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_key_falls_back_to_parent() {
+        let mut library = TemplateLibrary::default();
+        library.register(
+            TemplateLibrary::from_json(
+                r#"{"templates": {"response.test": [{"text": "{receiver} nods at {sender}"}]}}"#,
+            )
+            .unwrap(),
+        );
+
+        let variants = library.variants("response.test.shared.honor").unwrap();
+        assert_eq!(variants.len(), 1);
+        assert_eq!(
+            fill(&variants[0].text, &[("receiver", "Aqua"), ("sender", "Ignis")]),
+            "Aqua nods at Ignis"
+        );
+        assert!(library.variants("unknown.key").is_none());
+        assert!(TemplateLibrary::from_json(r#"{"templates": {"a": [{"text": "x", "weight": 0}]}}"#).is_err());
+    }
+
+    #[test]
+    fn test_fill_does_not_expand_placeholders_inside_values() {
+        let placeholders = [("sender", "{receiver}"), ("receiver", "Aqua"), ("relationship", "{close} friends")];
+        assert_eq!(
+            fill("{sender} greets {receiver} as {relationship}, {unknown} {", &placeholders),
+            "{receiver} greets Aqua as {close} friends, {unknown} {"
+        );
+
+        // The order of the placeholders does not matter
+        let reversed: Vec<_> = placeholders.iter().rev().copied().collect();
+        assert_eq!(fill("{sender} and {receiver}", &reversed), "{receiver} and Aqua");
+    }
+
+    #[test]
+    fn test_choose_handles_weights_that_overflow_u32() {
+        let variants = TemplateLibrary::from_json(&format!(
+            r#"{{"templates": {{"a": [{{"text": "x", "weight": {max}}}, {{"text": "y", "weight": {max}}}]}}}}"#,
+            max = u32::MAX
+        ))
+        .unwrap()
+        .remove("a")
+        .unwrap();
+        for _ in 0..20 {
+            assert!(choose(&variants).is_some());
+        }
+        assert!(choose(&variants[..0]).is_none());
+    }
+}
@@ -1,4 +1,21 @@
-use crate::dragon::DragonElement;
+use crate::element;
+use crate::recorder::RingBuffer;
+use std::collections::HashMap;
+
+/// Drift pressure gained from one fully intense exchange by a newly hatched dragon
+const BASE_DRIFT_RATE: f64 = 0.6;
+
+/// Age at which a dragon is half as open to changing its values as a hatchling
+const DRIFT_RESISTANCE_AGE: f64 = 5.0;
+
+/// Maximum number of value shifts kept in a dragon's history
+const MAX_DRIFT_HISTORY: usize = 200;
+
+/// Every value a dragon holds, in the order they are listed to players
+pub const VALUE_NAMES: [&str; 10] = [
+    "honor", "freedom", "tradition", "growth", "community",
+    "achievement", "harmony", "power", "wisdom", "protection",
+];
 
 /*
 Thought space on values:
@@ -72,6 +89,157 @@ impl DragonValues {
     pub fn protection(&self) -> u32 {
         Self::get_second_from_axis(self.achievement_vs_protection)
     }
+
+    /// Get a value's score by name
+    pub fn get(&self, name: &str) -> Option<u32> {
+        match name {
+            "honor" => Some(self.honor),
+            "wisdom" => Some(self.wisdom),
+            "freedom" => Some(self.freedom()),
+            "community" => Some(self.community()),
+            "tradition" => Some(self.tradition()),
+            "growth" => Some(self.growth()),
+            "power" => Some(self.power()),
+            "harmony" => Some(self.harmony()),
+            "achievement" => Some(self.achievement()),
+            "protection" => Some(self.protection()),
+            _ => None,
+        }
+    }
+
+    /// Axis a value lies on, named by its first value, and which way raising the value moves it
+    /// e.g. ("tradition", -1) for growth; values off any axis are their own axis
+    pub fn axis(name: &str) -> Option<(&'static str, i32)> {
+        match name {
+            "honor" => Some(("honor", 1)),
+            "wisdom" => Some(("wisdom", 1)),
+            "freedom" => Some(("freedom", 1)),
+            "community" => Some(("freedom", -1)),
+            "tradition" => Some(("tradition", 1)),
+            "growth" => Some(("tradition", -1)),
+            "power" => Some(("power", 1)),
+            "harmony" => Some(("power", -1)),
+            "achievement" => Some(("achievement", 1)),
+            "protection" => Some(("achievement", -1)),
+            _ => None,
+        }
+    }
+
+    /// Move a value by delta, clamped to 0-100
+    /// For values on an axis this moves the axis, so raising growth lowers tradition
+    pub fn adjust(&mut self, name: &str, delta: i32) {
+        fn shift(score: &mut u32, delta: i32) {
+            *score = (*score as i32 + delta).clamp(0, 100) as u32;
+        }
+        match name {
+            "honor" => shift(&mut self.honor, delta),
+            "wisdom" => shift(&mut self.wisdom, delta),
+            "freedom" => shift(&mut self.freedom_vs_community, delta),
+            "community" => shift(&mut self.freedom_vs_community, -delta),
+            "tradition" => shift(&mut self.tradition_vs_growth, delta),
+            "growth" => shift(&mut self.tradition_vs_growth, -delta),
+            "power" => shift(&mut self.power_vs_harmony, delta),
+            "harmony" => shift(&mut self.power_vs_harmony, -delta),
+            "achievement" => shift(&mut self.achievement_vs_protection, delta),
+            "protection" => shift(&mut self.achievement_vs_protection, -delta),
+            _ => {}
+        }
+    }
+}
+
+/// A recorded change in one of a dragon's values
+#[derive(Debug, Clone)]
+pub struct ValueShift {
+    /// Clan day on which the shift happened
+    pub day: u32,
+    /// Name of the value that moved, e.g. "tradition"
+    pub value: String,
+    /// Score of the value before the shift
+    pub old_score: u32,
+    /// Score of the value after the shift
+    pub new_score: u32,
+    /// Dragon whose influence caused the shift
+    pub influencer: String,
+}
+
+/// Slow change of a dragon's values through social influence
+/// Exchanges build up fractional pressure on a value's axis; once it reaches a whole point the axis moves,
+/// so being drawn to growth and to tradition cancels out
+#[derive(Debug, Clone)]
+pub struct ValueDrift {
+    /// Pressure by axis, positive toward the axis's first value
+    pressure: HashMap<&'static str, f64>,
+    history: RingBuffer<ValueShift>,
+}
+
+impl Default for ValueDrift {
+    fn default() -> Self {
+        ValueDrift {
+            pressure: HashMap::new(),
+            history: RingBuffer::new(MAX_DRIFT_HISTORY),
+        }
+    }
+}
+
+impl ValueDrift {
+    /// How open a dragon of the given age is to changing its values (1.0 for hatchlings)
+    pub fn susceptibility(age: u32) -> f64 {
+        DRIFT_RESISTANCE_AGE / (DRIFT_RESISTANCE_AGE + age as f64)
+    }
+
+    /// React to another dragon expressing a value
+    /// A pleasant exchange draws the listener toward the value, a strongly unpleasant one pushes them away
+    #[allow(clippy::too_many_arguments)]
+    pub fn influence(
+        &mut self,
+        values: &mut DragonValues,
+        value_name: &str,
+        opinion_change: i32,
+        intensity: u32,
+        age: u32,
+        influencer: &str,
+        day: u32,
+    ) {
+        let direction = if opinion_change > 0 {
+            1.0
+        } else if opinion_change <= -5 {
+            -1.0
+        } else {
+            return;
+        };
+
+        let (old_score, (axis, sign)) = match (values.get(value_name), DragonValues::axis(value_name)) {
+            (Some(score), Some(axis)) => (score, axis),
+            _ => return,
+        };
+
+        let rate = BASE_DRIFT_RATE * (intensity as f64 / 100.0) * Self::susceptibility(age);
+        let pressure = self.pressure.entry(axis).or_insert(0.0);
+        *pressure += direction * rate * sign as f64;
+
+        let whole = pressure.trunc();
+        if whole == 0.0 {
+            return;
+        }
+        *pressure -= whole;
+
+        values.adjust(axis, whole as i32);
+        let new_score = values.get(value_name).unwrap_or(old_score);
+        if new_score != old_score {
+            self.history.push(ValueShift {
+                day,
+                value: value_name.to_string(),
+                old_score,
+                new_score,
+                influencer: influencer.to_string(),
+            });
+        }
+    }
+
+    /// Recorded value shifts, oldest first
+    pub fn history(&self) -> impl Iterator<Item = &ValueShift> {
+        self.history.iter()
+    }
 }
 
 // Removed calculate_value_alignment and get_value - no longer used since relationships are emergent
@@ -84,7 +252,8 @@ fn random_value() -> u32 {
     ((value1 + value2) / 2) as u32
 }
 
-pub fn generate_dragon_values(element: Option<DragonElement>) -> DragonValues {
+/// Generate random values nudged by a dragon's element, and at reduced weight by its secondary element
+pub fn generate_dragon_values(element: Option<&str>, secondary_element: Option<&str>) -> DragonValues {
     // Generate random axis values (0-100)
     // For axes: higher = first value, lower = second value
     let mut values = DragonValues {
@@ -96,42 +265,11 @@ pub fn generate_dragon_values(element: Option<DragonElement>) -> DragonValues {
         achievement_vs_protection: random_value(),
     };
 
-    // Element-based value adjustments
+    // Element-based value adjustments from the element registry
     // Adjust axes by moving toward the preferred value
     if let Some(element) = element {
-        match element {
-            DragonElement::Fire => {
-                // Higher power, higher achievement, lower harmony
-                values.power_vs_harmony = (values.power_vs_harmony + 20).min(100);
-                values.achievement_vs_protection = (values.achievement_vs_protection + 15).min(100);
-            }
-            DragonElement::Water => {
-                // Higher harmony, higher protection, higher wisdom
-                values.power_vs_harmony = values.power_vs_harmony.saturating_sub(20);
-                values.achievement_vs_protection = values.achievement_vs_protection.saturating_sub(15);
-                values.wisdom = (values.wisdom + 10).min(100);
-            }
-            DragonElement::Earth => {
-                // Higher tradition, higher honor, lower growth
-                values.tradition_vs_growth = (values.tradition_vs_growth + 25).min(100);
-                values.honor = (values.honor + 15).min(100);
-            }
-            DragonElement::Wind => {
-                // Higher freedom, higher growth, lower tradition
-                values.freedom_vs_community = (values.freedom_vs_community + 25).min(100);
-                values.tradition_vs_growth = values.tradition_vs_growth.saturating_sub(20);
-            }
-            DragonElement::Lightning => {
-                // Higher achievement, higher power, lower harmony
-                values.achievement_vs_protection = (values.achievement_vs_protection + 20).min(100);
-                values.power_vs_harmony = (values.power_vs_harmony + 15).min(100);
-            }
-            DragonElement::Ice => {
-                // Higher wisdom, higher harmony, lower community
-                values.wisdom = (values.wisdom + 25).min(100);
-                values.power_vs_harmony = values.power_vs_harmony.saturating_sub(15);
-                values.freedom_vs_community = values.freedom_vs_community.saturating_sub(15);
-            }
+        for (value_name, delta) in element::blended_value_nudges(element, secondary_element) {
+            values.adjust(&value_name, delta);
         }
     }
 
@@ -145,3 +283,67 @@ pub fn generate_dragon_values(element: Option<DragonElement>) -> DragonValues {
 
     values
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn middling() -> DragonValues {
+        DragonValues {
+            honor: 50,
+            wisdom: 50,
+            freedom_vs_community: 50,
+            tradition_vs_growth: 50,
+            power_vs_harmony: 50,
+            achievement_vs_protection: 50,
+        }
+    }
+
+    /// Expose a dragon of the given age to the value in pleasant, fully intense exchanges
+    fn expose(drift: &mut ValueDrift, values: &mut DragonValues, value: &str, times: u32, age: u32) {
+        for day in 0..times {
+            drift.influence(values, value, 5, 100, age, "Ignis", day);
+        }
+    }
+
+    #[test]
+    fn test_repeated_exposure_moves_values_and_age_resists() {
+        let mut drift = ValueDrift::default();
+        let mut values = middling();
+        expose(&mut drift, &mut values, "growth", 10, 0);
+        // Ten exchanges at 0.6 a point each, give or take the last fraction
+        assert!((55..=56).contains(&values.growth()));
+        assert_eq!(drift.history().count() as u32, values.growth() - 50);
+        let last = drift.history().last().unwrap();
+        assert_eq!((last.value.as_str(), last.new_score), ("growth", values.growth()));
+
+        let mut elder_drift = ValueDrift::default();
+        let mut elder = middling();
+        expose(&mut elder_drift, &mut elder, "growth", 10, 20);
+        assert!(elder.growth() > 50 && elder.growth() < values.growth());
+
+        // Mild displeasure leaves values alone, strong displeasure pushes the dragon away
+        let mut drift = ValueDrift::default();
+        let mut values = middling();
+        for day in 0..10 {
+            drift.influence(&mut values, "honor", -1, 100, 0, "Ignis", day);
+        }
+        assert_eq!(values.honor, 50);
+        for day in 0..10 {
+            drift.influence(&mut values, "honor", -10, 100, 0, "Ignis", day);
+        }
+        assert!((44..=45).contains(&values.honor));
+    }
+
+    #[test]
+    fn test_opposing_values_on_an_axis_cancel_out() {
+        let mut drift = ValueDrift::default();
+        let mut values = middling();
+        for day in 0..20 {
+            let value = if day % 2 == 0 { "tradition" } else { "growth" };
+            drift.influence(&mut values, value, 5, 100, 0, "Ignis", day);
+        }
+        assert_eq!(values.tradition(), 50);
+        assert_eq!(drift.history().count(), 0);
+    }
+}
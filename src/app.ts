@@ -364,6 +364,13 @@ function renderRelationships() {
     return;
   }
 
+  // Fetch every opinion in one call rather than one per pair
+  const matrix = clanService.getRelationshipMatrix();
+  if (!matrix || matrix.size !== dragons.length) {
+    relationshipsList.innerHTML = '<p>Relationships are unavailable.</p>';
+    return;
+  }
+
   // Create relationship matrix
   let html = '<table class="relationship-matrix"><thead><tr><th></th>';
   dragons.forEach(d => {
@@ -377,16 +384,12 @@ function renderRelationships() {
       if (dragon1Index === dragon2Index) {
         html += '<td class="self">—</td>';
       } else {
-        try {
-          const opinion = clanService.getOpinion(dragon1Index, dragon2Index) || 0;
-          const relationship = clanService.getRelationshipInfo(dragon1Index, dragon2Index) || 'Unknown relationship';
-          const status = relationship.split(' ')[0]; // Get status word
-          const className = opinion > 50 ? 'positive' : opinion < -50 ? 'negative' : 'neutral';
-          html += `<td class="${className}" title="${relationship}">${opinion}</td>`;
-        } catch (error) {
-          console.error(`Error getting relationship between ${dragon.name} and ${other.name}:`, error);
-          html += '<td class="neutral" title="Error loading relationship">0</td>';
-        }
+        const entry = dragon1Index * matrix.size + dragon2Index;
+        const opinion = matrix.opinions[entry];
+        const interactions = matrix.interactionCounts[entry];
+        const className = opinion > 50 ? 'positive' : opinion < -50 ? 'negative' : 'neutral';
+        const title = `${dragon.name} → ${other.name}: ${interactions} interaction${interactions === 1 ? '' : 's'}`;
+        html += `<td class="${className}" title="${title}">${opinion}</td>`;
       }
     });
     html += '</tr>';
@@ -4,6 +4,7 @@ import {
   DragonElement, 
   InteractionEvent, 
   ClanStats, 
+  RelationshipMatrix,
   initWasm,
   EventType,
   subscribeToEvent,
@@ -18,7 +19,21 @@ export type ClanServiceEvent =
   | { type: 'dragon-added'; dragon: DragonInfo }
   | { type: 'dragon-removed'; dragonName: string }
   | { type: 'interaction-simulated'; event: InteractionEvent }
+  | { type: 'ability-used'; event: InteractionEvent }
   | { type: 'clan-reset'; clanName: string; dragonCount: number }
+  | {
+      type: 'bond-formed';
+      dragon1Index: number;
+      dragon2Index: number;
+      dragon1Name: string;
+      dragon2Name: string;
+      bondType: string;
+    }
+  | { type: 'clan-renamed'; oldName: string; clanName: string }
+  | { type: 'faction-formed'; day: number; factionName: string; members: string[] }
+  | { type: 'faction-split'; day: number; factionName: string; members: string[]; splinters: string[] }
+  | { type: 'faction-merged'; day: number; factionName: string; members: string[]; absorbed: string[] }
+  | { type: 'faction-dissolved'; day: number; factionName: string; members: string[] }
   | { type: 'error'; message: string; error?: Error };
 
 /**
@@ -96,6 +111,11 @@ export interface IClanService {
    */
   getOpinion(dragon1Index: number, dragon2Index: number): number | null;
 
+  /**
+   * Get every dragon's opinion of every other and their interaction counts in a single call
+   */
+  getRelationshipMatrix(): RelationshipMatrix | null;
+
   /**
    * Get character info for a dragon
    */
@@ -152,7 +172,14 @@ export class ClanService implements IClanService {
       { rust: EventType.DragonAdded, ts: 'dragon-added' },
       { rust: EventType.DragonRemoved, ts: 'dragon-removed' },
       { rust: EventType.InteractionSimulated, ts: 'interaction-simulated' },
+      { rust: EventType.AbilityUsed, ts: 'ability-used' },
       { rust: EventType.ClanReset, ts: 'clan-reset' },
+      { rust: EventType.BondFormed, ts: 'bond-formed' },
+      { rust: EventType.ClanRenamed, ts: 'clan-renamed' },
+      { rust: EventType.FactionFormed, ts: 'faction-formed' },
+      { rust: EventType.FactionSplit, ts: 'faction-split' },
+      { rust: EventType.FactionMerged, ts: 'faction-merged' },
+      { rust: EventType.FactionDissolved, ts: 'faction-dissolved' },
     ];
 
     for (const mapping of eventMappings) {
@@ -186,6 +213,12 @@ export class ClanService implements IClanService {
               event: rustEvent.event,
             };
             break;
+          case 'ability-used':
+            tsEvent = {
+              type: 'ability-used',
+              event: rustEvent.event,
+            };
+            break;
           case 'clan-reset':
             tsEvent = {
               type: 'clan-reset',
@@ -193,6 +226,57 @@ export class ClanService implements IClanService {
               dragonCount: rustEvent.dragonCount || 0,
             };
             break;
+          case 'bond-formed':
+            tsEvent = {
+              type: 'bond-formed',
+              dragon1Index: rustEvent.dragon1Index ?? 0,
+              dragon2Index: rustEvent.dragon2Index ?? 0,
+              dragon1Name: rustEvent.dragon1Name || '',
+              dragon2Name: rustEvent.dragon2Name || '',
+              bondType: rustEvent.bondType || '',
+            };
+            break;
+          case 'clan-renamed':
+            tsEvent = {
+              type: 'clan-renamed',
+              oldName: rustEvent.oldName || '',
+              clanName: rustEvent.clanName || '',
+            };
+            break;
+          case 'faction-formed':
+            tsEvent = {
+              type: 'faction-formed',
+              day: rustEvent.day || 0,
+              factionName: rustEvent.factionName || '',
+              members: rustEvent.members || [],
+            };
+            break;
+          case 'faction-dissolved':
+            tsEvent = {
+              type: 'faction-dissolved',
+              day: rustEvent.day || 0,
+              factionName: rustEvent.factionName || '',
+              members: rustEvent.members || [],
+            };
+            break;
+          case 'faction-split':
+            tsEvent = {
+              type: 'faction-split',
+              day: rustEvent.day || 0,
+              factionName: rustEvent.factionName || '',
+              members: rustEvent.members || [],
+              splinters: rustEvent.splinters || [],
+            };
+            break;
+          case 'faction-merged':
+            tsEvent = {
+              type: 'faction-merged',
+              day: rustEvent.day || 0,
+              factionName: rustEvent.factionName || '',
+              members: rustEvent.members || [],
+              absorbed: rustEvent.absorbed || [],
+            };
+            break;
           default:
             return; // Unknown event type
         }
@@ -353,6 +437,13 @@ export class ClanService implements IClanService {
     return this.wasmService.getOpinion(dragon1Index, dragon2Index);
   }
 
+  /**
+   * Get every dragon's opinion of every other and their interaction counts in a single call
+   */
+  getRelationshipMatrix(): RelationshipMatrix | null {
+    return this.wasmService.getRelationshipMatrix();
+  }
+
   /**
    * Get character info for a dragon
    */
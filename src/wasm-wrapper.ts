@@ -1,7 +1,6 @@
 import init, { 
   ClanService as WasmClanService,
   DragonInfo as WasmDragonInfo,
-  DragonElement as WasmDragonElement,
   InteractionEvent as WasmInteractionEvent,
   ClanStats as WasmClanStats,
   generate_dragon_name as wasmGenerateDragonName,
@@ -27,7 +26,8 @@ export async function initWasm() {
 }
 
 // Re-export types
-export type DragonElement = 'Fire' | 'Water' | 'Earth' | 'Wind' | 'Lightning' | 'Ice';
+// The built-in elements; elements registered at runtime with load_elements are plain strings
+export type DragonElement = 'Fire' | 'Water' | 'Earth' | 'Wind' | 'Lightning' | 'Ice' | (string & {});
 
 // Dragon info interface (read-only, no internal Dragon object exposed)
 export interface DragonInfo {
@@ -41,10 +41,17 @@ export interface DragonInfo {
 export interface InteractionEvent {
   description: string;
   dragon1Index: number;
-  dragon2Index: number;
+  dragon2Index: number | null;
   opinionChange: number;
 }
 
+// Every dragon's opinion of every other, row-major: entry i * size + j is dragon i's view of dragon j
+export interface RelationshipMatrix {
+  size: number;
+  opinions: Int32Array;
+  interactionCounts: Uint32Array;
+}
+
 // Clan stats interface
 export interface ClanStats {
   name: string;
@@ -172,6 +179,21 @@ export class ClanService {
     return this.wasmService.get_opinion(dragon1Index, dragon2Index) || null;
   }
 
+  /**
+   * Get every dragon's opinion of every other and their interaction counts in a single call
+   */
+  getRelationshipMatrix(): RelationshipMatrix | null {
+    const matrix = this.wasmService.get_relationship_matrix();
+    if (!matrix) return null;
+    const result = {
+      size: matrix.size, // Property, not method
+      opinions: matrix.opinions, // Int32Array
+      interactionCounts: matrix.interaction_counts, // Uint32Array
+    };
+    matrix.free();
+    return result;
+  }
+
   /**
    * Get character info for a dragon
    */
@@ -198,7 +220,7 @@ export class ClanService {
     return {
       description: wasmEvent.description, // Property, not method
       dragon1Index: wasmEvent.dragon1_index, // Property, not method
-      dragon2Index: wasmEvent.dragon2_index, // Property, not method
+      dragon2Index: wasmEvent.dragon2_index ?? null, // Property, not method; null for untargeted abilities
       opinionChange: wasmEvent.opinion_change, // Property, not method
     };
   }